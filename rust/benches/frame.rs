@@ -0,0 +1,53 @@
+//! Per-frame CPU cost of laying out and tessellating the page
+//!
+//! Runs `AlexandriaApp::ui` headlessly at a few window sizes, idle and with a
+//! product card hovered, and prints the mean and p95 time per frame. Run with
+//! `cargo bench --bench frame`.
+
+use std::time::{Duration, Instant};
+
+use alexandria_landing::AlexandriaApp;
+use egui::{Pos2, Rect, Vec2};
+
+const WARMUP: usize = 120;
+const FRAMES: usize = 1000;
+
+fn input(size: Vec2, pointer: Option<Pos2>) -> egui::RawInput {
+    let mut raw = egui::RawInput { screen_rect: Some(Rect::from_min_size(Pos2::ZERO, size)), ..Default::default() };
+    if let Some(pos) = pointer {
+        raw.events.push(egui::Event::PointerMoved(pos));
+    }
+    raw
+}
+
+fn bench(name: &str, size: Vec2, pointer: Option<Pos2>) {
+    let ctx = egui::Context::default();
+    let mut app = AlexandriaApp::default();
+    let mut frame = |ctx: &egui::Context| {
+        let output = ctx.run(input(size, pointer), |ctx| app.ui(ctx));
+        std::hint::black_box(ctx.tessellate(output.shapes, output.pixels_per_point));
+    };
+    for _ in 0..WARMUP {
+        frame(&ctx);
+    }
+    let mut times: Vec<Duration> = (0..FRAMES)
+        .map(|_| {
+            let start = Instant::now();
+            frame(&ctx);
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    let mean = times.iter().sum::<Duration>() / FRAMES as u32;
+    let p95 = times[FRAMES * 95 / 100];
+    println!("{name:<24} mean {:>7.1} µs   p95 {:>7.1} µs", mean.as_secs_f64() * 1e6, p95.as_secs_f64() * 1e6);
+}
+
+fn main() {
+    let desktop = Vec2::new(1200.0, 800.0);
+    let mobile = Vec2::new(380.0, 800.0);
+    bench("desktop idle", desktop, None);
+    // Over the first product card, so glow, scanline and tag effects run
+    bench("desktop card hovered", desktop, Some(Pos2::new(300.0, 560.0)));
+    bench("mobile idle", mobile, None);
+}
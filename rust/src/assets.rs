@@ -0,0 +1,177 @@
+//! Resolving asset paths (logos, fonts) to URLs
+//!
+//! Content refers to assets by site path, e.g. `/logos/projects/alex.png`. An
+//! [`AssetResolver`] maps those onto wherever the assets are actually served:
+//! the page's own directory by default, or a configured base that may be
+//! relative to the page, an absolute URL (a CDN), or an `ic://<canister-id>/`
+//! URL for a canister's asset gateway. The base comes from, in order of
+//! precedence, `set_asset_base` called from JS, a
+//! `<meta name="asset-base" content="...">` tag, or [`crate::ASSET_BASE`].
+
+/// Gateway that serves `ic://` canister URLs
+pub(crate) const IC_GATEWAY: &str = "icp0.io";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct AssetResolver {
+    /// Absolute base URL ending in `/`, or empty when there is no page URL to
+    /// resolve against (native builds), in which case paths pass through
+    base: String,
+}
+
+impl AssetResolver {
+    /// Resolver for a page at `page_url` with an optional configured `base`
+    pub fn new(page_url: &str, base: Option<&str>) -> Self {
+        let base = match base.map(str::trim).filter(|b| !b.is_empty()) {
+            Some(base) => resolve_reference(page_url, base),
+            None => directory(page_url).to_owned(),
+        };
+        let base = if base.is_empty() || base.ends_with('/') { base } else { format!("{base}/") };
+        Self { base }
+    }
+
+    /// URL for an asset. Absolute and `ic://` paths ignore the base; site paths
+    /// and relative paths are both taken relative to it.
+    pub fn resolve(&self, path: &str) -> String {
+        if is_absolute(path) {
+            return path.to_owned();
+        }
+        if let Some(url) = canister_url(path) {
+            return url;
+        }
+        if self.base.is_empty() {
+            return path.to_owned();
+        }
+        format!("{}{}", self.base, path.trim_start_matches('/'))
+    }
+}
+
+fn is_absolute(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+/// `ic://<canister-id>/<path>` to the canister's gateway URL
+fn canister_url(url: &str) -> Option<String> {
+    let rest = url.strip_prefix("ic://")?;
+    let (id, path) = rest.split_once('/').unwrap_or((rest, ""));
+    (!id.is_empty()).then(|| format!("https://{id}.{IC_GATEWAY}/{path}"))
+}
+
+/// `scheme://host` of an absolute URL, or empty
+fn origin(url: &str) -> &str {
+    let Some(scheme_end) = url.find("://") else { return "" };
+    let host_start = scheme_end + 3;
+    let host_end = url[host_start..].find(['/', '?', '#']).map_or(url.len(), |i| host_start + i);
+    &url[..host_end]
+}
+
+/// Everything up to and including the last `/` of the URL's path, or empty
+fn directory(url: &str) -> &str {
+    let origin = origin(url);
+    if origin.is_empty() {
+        return "";
+    }
+    let path_end = url[origin.len()..].find(['?', '#']).map_or(url.len(), |i| origin.len() + i);
+    match url[origin.len()..path_end].rfind('/') {
+        Some(i) => &url[..origin.len() + i + 1],
+        // `https://host` with no path at all
+        None => origin,
+    }
+}
+
+/// Resolve a configured base against the page URL, like a browser resolves a link
+fn resolve_reference(page_url: &str, reference: &str) -> String {
+    if is_absolute(reference) {
+        return reference.to_owned();
+    }
+    if let Some(url) = canister_url(reference) {
+        return url;
+    }
+    if let Some(rest) = reference.strip_prefix("//") {
+        let scheme = page_url.split_once("://").map_or("https", |(s, _)| s);
+        return format!("{scheme}://{rest}");
+    }
+    if reference.starts_with('/') {
+        return format!("{}{}", origin(page_url), reference);
+    }
+    let dir = directory(page_url);
+    if dir.is_empty() {
+        return reference.to_owned();
+    }
+    let dir = if dir.ends_with('/') { dir.to_owned() } else { format!("{dir}/") };
+    format!("{dir}{reference}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT_PAGE: &str = "https://lbry.app/";
+    const SUB_PAGE: &str = "https://example.org/alexandria/index.html?ref=x";
+    const LOGO: &str = "/logos/projects/alex.png";
+
+    fn resolve(page: &str, base: Option<&str>, path: &str) -> String {
+        AssetResolver::new(page, base).resolve(path)
+    }
+
+    #[test]
+    fn default_base_is_the_page_directory() {
+        assert_eq!(resolve(ROOT_PAGE, None, LOGO), "https://lbry.app/logos/projects/alex.png");
+        assert_eq!(resolve(SUB_PAGE, None, LOGO), "https://example.org/alexandria/logos/projects/alex.png");
+        assert_eq!(resolve("https://lbry.app", None, LOGO), "https://lbry.app/logos/projects/alex.png");
+    }
+
+    #[test]
+    fn relative_base() {
+        assert_eq!(resolve(ROOT_PAGE, Some("static"), LOGO), "https://lbry.app/static/logos/projects/alex.png");
+        assert_eq!(resolve(SUB_PAGE, Some("static/"), LOGO), "https://example.org/alexandria/static/logos/projects/alex.png");
+    }
+
+    #[test]
+    fn root_relative_base() {
+        assert_eq!(resolve(SUB_PAGE, Some("/cdn/"), LOGO), "https://example.org/cdn/logos/projects/alex.png");
+        assert_eq!(resolve(ROOT_PAGE, Some("/"), LOGO), "https://lbry.app/logos/projects/alex.png");
+    }
+
+    #[test]
+    fn absolute_base() {
+        let cdn = Some("https://cdn.example.net/alexandria");
+        assert_eq!(resolve(ROOT_PAGE, cdn, LOGO), "https://cdn.example.net/alexandria/logos/projects/alex.png");
+        assert_eq!(resolve(SUB_PAGE, cdn, LOGO), "https://cdn.example.net/alexandria/logos/projects/alex.png");
+        assert_eq!(resolve(SUB_PAGE, Some("//cdn.example.net/"), LOGO), "https://cdn.example.net/logos/projects/alex.png");
+    }
+
+    #[test]
+    fn canister_base() {
+        // A custom domain page loading assets from the canister's raw gateway URL
+        let base = Some("ic://z6d57-uyaaa-aaaau-ac24a-cai");
+        assert_eq!(resolve(ROOT_PAGE, base, LOGO), "https://z6d57-uyaaa-aaaau-ac24a-cai.icp0.io/logos/projects/alex.png");
+        let base = Some("ic://z6d57-uyaaa-aaaau-ac24a-cai/assets/");
+        assert_eq!(resolve(SUB_PAGE, base, "fonts/a.ttf"), "https://z6d57-uyaaa-aaaau-ac24a-cai.icp0.io/assets/fonts/a.ttf");
+    }
+
+    #[test]
+    fn absolute_and_canister_paths_ignore_the_base() {
+        for base in [None, Some("static/"), Some("/cdn/"), Some("https://cdn.example.net/"), Some("ic://aaaaa-aa/")] {
+            assert_eq!(resolve(SUB_PAGE, base, "https://other.org/x.png"), "https://other.org/x.png");
+            assert_eq!(resolve(SUB_PAGE, base, "ic://ryjl3-tyaaa-aaaaa-aaaba-cai/logo.svg"), "https://ryjl3-tyaaa-aaaaa-aaaba-cai.icp0.io/logo.svg");
+        }
+    }
+
+    #[test]
+    fn relative_paths_join_the_base() {
+        assert_eq!(resolve(SUB_PAGE, None, "logos/x.png"), "https://example.org/alexandria/logos/x.png");
+        assert_eq!(resolve(ROOT_PAGE, Some("https://cdn.example.net/a/"), "x.png"), "https://cdn.example.net/a/x.png");
+    }
+
+    #[test]
+    fn blank_base_falls_back_to_the_page() {
+        assert_eq!(AssetResolver::new(SUB_PAGE, Some("  ")), AssetResolver::new(SUB_PAGE, None));
+    }
+
+    #[test]
+    fn no_page_url_passes_site_paths_through() {
+        assert_eq!(resolve("", None, LOGO), LOGO);
+        assert_eq!(resolve("", Some("https://cdn.example.net/"), LOGO), "https://cdn.example.net/logos/projects/alex.png");
+        assert_eq!(resolve("", Some("ic://aaaaa-aa"), LOGO), "https://aaaaa-aa.icp0.io/logos/projects/alex.png");
+    }
+}
@@ -0,0 +1,227 @@
+//! Render the page as a frame sequence, for promo videos
+//!
+//! ```text
+//! cargo run --release --bin frames -- [--script FILE] [--from SECONDS] [--to SECONDS]
+//!     [--fps N] [--size WxH] [--scale N] [--format png|apng] [--out PATH] [--assets DIR]
+//! ```
+//!
+//! The page runs on a simulated clock that steps exactly `1 / fps` seconds a
+//! frame from zero, with the pointer driven by an
+//! [`alexandria_landing::Script`], so the same arguments always give the same
+//! frames. Frames between `--from` and `--to` (by default, the end of the
+//! script) are written as `frame-00000.png`, `frame-00001.png`, … into the
+//! `--out` directory (default `frames/`), or with `--format apng` as one
+//! looping animated PNG (default `frames.png`). `--size` is in points,
+//! 1920x1080 by default, and `--scale` sets pixels per point.
+
+// Renders with the native-only offscreen renderer, so it's a no-op on wasm
+#[cfg(target_arch = "wasm32")]
+fn main() {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> std::process::ExitCode {
+    cli::run()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod cli {
+    use std::fs::File;
+    use std::io::BufWriter;
+    use std::path::{Path, PathBuf};
+    use std::process::ExitCode;
+
+    use alexandria_landing::{AlexandriaApp, Offscreen, Script};
+    use egui::{Event, MouseWheelUnit, Pos2, Vec2};
+
+    const USAGE: &str = "usage: frames [--script FILE] [--from SECONDS] [--to SECONDS] [--fps N] [--size WxH] [--scale N] [--format png|apng] [--out PATH] [--assets DIR]";
+
+    /// Frames run at time zero before the first one counts, so the brand fonts
+    /// and logos are in from the start
+    const WARMUP_FRAMES: usize = 3;
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum Format {
+        Png,
+        Apng,
+    }
+
+    struct Options {
+        script: Script,
+        from: f64,
+        to: Option<f64>,
+        fps: u16,
+        size: Vec2,
+        scale: f32,
+        format: Format,
+        out: Option<PathBuf>,
+        assets: PathBuf,
+    }
+
+    fn seconds(arg: &str, value: String) -> Result<f64, String> {
+        value.trim_end_matches('s').parse().ok().filter(|s: &f64| s.is_finite() && *s >= 0.0).ok_or_else(|| format!("{arg} needs a time in seconds"))
+    }
+
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+        let public = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("public");
+        let mut options = Options {
+            script: Script::default(),
+            from: 0.0,
+            to: None,
+            fps: 30,
+            size: Vec2::new(1920.0, 1080.0),
+            scale: 1.0,
+            format: Format::Png,
+            out: None,
+            assets: public,
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--script" => {
+                    let path = value()?;
+                    let source = std::fs::read_to_string(&path).map_err(|err| format!("couldn't read {path}: {err}"))?;
+                    options.script = Script::parse(&source).map_err(|err| format!("{path}, {err}"))?;
+                }
+                "--from" => options.from = seconds(&arg, value()?)?,
+                "--to" => options.to = Some(seconds(&arg, value()?)?),
+                "--fps" => options.fps = value()?.parse().ok().filter(|fps| (1..=240).contains(fps)).ok_or("--fps needs a whole number from 1 to 240")?,
+                "--size" => {
+                    let size = value()?;
+                    let parsed = size.split_once('x').and_then(|(w, h)| Some(Vec2::new(w.parse().ok()?, h.parse().ok()?)));
+                    options.size = parsed.filter(|s| s.min_elem() >= 1.0).ok_or_else(|| format!("expected a size like 1920x1080, found {size:?}"))?;
+                }
+                "--scale" => options.scale = value()?.parse().ok().filter(|s: &f32| *s > 0.0).ok_or("--scale needs a positive number")?,
+                "--format" => {
+                    options.format = match value()?.as_str() {
+                        "png" => Format::Png,
+                        "apng" => Format::Apng,
+                        other => return Err(format!("unknown format {other:?}")),
+                    }
+                }
+                "--out" => options.out = Some(value()?.into()),
+                "--assets" => options.assets = value()?.into(),
+                other => return Err(format!("unknown argument {other:?}")),
+            }
+        }
+        Ok(options)
+    }
+
+    /// Where finished frames go
+    enum Output {
+        Pngs(PathBuf),
+        Apng(png::Writer<BufWriter<File>>),
+    }
+
+    impl Output {
+        fn create(format: Format, path: &Path, [width, height]: [u32; 2], frames: u32, fps: u16) -> Result<Self, String> {
+            let failed = |err: &dyn std::fmt::Display| format!("couldn't create {}: {err}", path.display());
+            match format {
+                Format::Png => {
+                    std::fs::create_dir_all(path).map_err(|err| failed(&err))?;
+                    Ok(Output::Pngs(path.to_owned()))
+                }
+                Format::Apng => {
+                    let file = File::create(path).map_err(|err| failed(&err))?;
+                    let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+                    encoder.set_color(png::ColorType::Rgba);
+                    encoder.set_depth(png::BitDepth::Eight);
+                    // Loops forever, each frame showing for 1/fps seconds
+                    encoder.set_animated(frames, 0).map_err(|err| failed(&err))?;
+                    encoder.set_frame_delay(1, fps).map_err(|err| failed(&err))?;
+                    Ok(Output::Apng(encoder.write_header().map_err(|err| failed(&err))?))
+                }
+            }
+        }
+
+        fn write(&mut self, index: u32, frame: image::RgbaImage) -> Result<(), String> {
+            match self {
+                Output::Pngs(dir) => {
+                    let path = dir.join(format!("frame-{index:05}.png"));
+                    frame.save(&path).map_err(|err| format!("couldn't write {}: {err}", path.display()))
+                }
+                Output::Apng(writer) => writer.write_image_data(frame.as_raw()).map_err(|err| format!("couldn't write frame {index}: {err}")),
+            }
+        }
+
+        fn finish(self) -> Result<(), String> {
+            match self {
+                Output::Pngs(_) => Ok(()),
+                Output::Apng(writer) => writer.finish().map_err(|err| format!("couldn't finish the animation: {err}")),
+            }
+        }
+    }
+
+    fn render(options: Options) -> Result<(), String> {
+        let fps = f64::from(options.fps);
+        let to = options.to.unwrap_or_else(|| options.script.end());
+        // Frame n is at exactly n / fps seconds, so no error builds up
+        let (first, last) = ((options.from * fps).round() as u32, (to * fps).round() as u32);
+        if last <= first {
+            return Err("nothing to render: pass a script or --to, ending after --from".into());
+        }
+
+        let mut offscreen = Offscreen::new(options.size, options.scale);
+        offscreen.load_assets(&options.assets).map_err(|err| format!("couldn't read assets from {}: {err}", options.assets.display()))?;
+        let out = options.out.unwrap_or_else(|| match options.format {
+            Format::Png => "frames".into(),
+            Format::Apng => "frames.png".into(),
+        });
+        let mut output = Output::create(options.format, &out, offscreen.pixel_size(), last - first, options.fps)?;
+
+        let mut app = AlexandriaApp::default();
+        app.set_time(0.0);
+        for _ in 0..WARMUP_FRAMES {
+            offscreen.run(0.0, Vec::new(), |ctx| app.ui(ctx));
+        }
+
+        // Scrolling needs the pointer over the page, so when nothing else holds
+        // it, it waits in the right margin
+        let parked = Pos2::new(options.size.x - 4.0, options.size.y / 2.0);
+        let mut previous = f64::NEG_INFINITY;
+        for n in 0..last {
+            let time = f64::from(n) / fps;
+            let scroll = options.script.scroll(previous, time);
+            previous = time;
+            let pointer = options.script.pointer(time, |target| app.hit_area(target));
+            let mut events = vec![match pointer.or((scroll != Vec2::ZERO).then_some(parked)) {
+                Some(pos) => Event::PointerMoved(pos),
+                None => Event::PointerGone,
+            }];
+            if scroll != Vec2::ZERO {
+                events.push(Event::MouseWheel { unit: MouseWheelUnit::Point, delta: -scroll, modifiers: Default::default() });
+            }
+
+            app.set_time(time);
+            offscreen.run(time, events, |ctx| app.ui(ctx));
+            if n >= first {
+                output.write(n - first, offscreen.image())?;
+            }
+        }
+        output.finish()?;
+        println!("{} frames at {} fps: {}", last - first, options.fps, out.display());
+        Ok(())
+    }
+
+    pub fn run() -> ExitCode {
+        alexandria_landing::init_logging();
+        let args: Vec<_> = std::env::args().skip(1).collect();
+        if args.iter().any(|a| a == "-h" || a == "--help") {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        let options = match parse(args.into_iter()) {
+            Ok(options) => options,
+            Err(err) => {
+                eprintln!("{err}\n{USAGE}");
+                return ExitCode::from(2);
+            }
+        };
+        match render(options) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Render the Open Graph cards: one for the home page and one per product
+//!
+//! ```text
+//! cargo run --release --bin og-cards -- [--out DIR] [--assets DIR] [--scale N] [--fallback-fonts]
+//! ```
+//!
+//! Cards are written to `public/og/` by default as `home.png` and
+//! `<product-slug>.png`, 1200×630 at `--scale 1`. Logos and the brand fonts
+//! are read from `public/`. Cards set in egui's fallback faces aren't fit to
+//! commit, so a missing brand font is an error unless `--fallback-fonts` says
+//! it's fine.
+
+// Renders with the native-only offscreen renderer, so it's a no-op on wasm
+#[cfg(target_arch = "wasm32")]
+fn main() {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> std::process::ExitCode {
+    cli::run()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod cli {
+    use std::path::PathBuf;
+    use std::process::ExitCode;
+
+    use alexandria_landing::{missing_brand_fonts, AlexandriaApp, Offscreen, SocialCard, CARD_SIZE};
+
+    const USAGE: &str = "usage: og-cards [--out DIR] [--assets DIR] [--scale N] [--fallback-fonts]";
+
+    /// Frames a card may take to settle (fonts bind a frame after they install)
+    const MAX_FRAMES: usize = 8;
+
+    struct Options {
+        out: PathBuf,
+        assets: PathBuf,
+        scale: f32,
+        fallback_fonts: bool,
+    }
+
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+        let public = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("public");
+        let mut options = Options { out: public.join("og"), assets: public, scale: 1.0, fallback_fonts: false };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--out" => options.out = value()?.into(),
+                "--assets" => options.assets = value()?.into(),
+                "--scale" => options.scale = value()?.parse().ok().filter(|s: &f32| *s > 0.0).ok_or("--scale needs a positive number")?,
+                "--fallback-fonts" => options.fallback_fonts = true,
+                other => return Err(format!("unknown argument {other:?}")),
+            }
+        }
+        Ok(options)
+    }
+
+    pub fn run() -> ExitCode {
+        alexandria_landing::init_logging();
+        let args: Vec<_> = std::env::args().skip(1).collect();
+        if args.iter().any(|a| a == "-h" || a == "--help") {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        let options = match parse(args.into_iter()) {
+            Ok(options) => options,
+            Err(err) => {
+                eprintln!("{err}\n{USAGE}");
+                return ExitCode::from(2);
+            }
+        };
+
+        let missing = missing_brand_fonts(&options.assets);
+        if !missing.is_empty() {
+            let fonts = missing.join(", ");
+            if !options.fallback_fonts {
+                eprintln!("brand fonts missing from {}: {fonts}\npass --fallback-fonts to render with egui's default faces anyway", options.assets.display());
+                return ExitCode::FAILURE;
+            }
+            log::warn!("brand fonts missing from {}, using fallback faces: {fonts}", options.assets.display());
+        }
+
+        let mut offscreen = Offscreen::new(CARD_SIZE, options.scale);
+        if let Err(err) = offscreen.load_assets(&options.assets) {
+            eprintln!("couldn't read assets from {}: {err}", options.assets.display());
+            return ExitCode::FAILURE;
+        }
+        if let Err(err) = std::fs::create_dir_all(&options.out) {
+            eprintln!("couldn't create {}: {err}", options.out.display());
+            return ExitCode::FAILURE;
+        }
+
+        let mut app = AlexandriaApp::default();
+        for card in SocialCard::all() {
+            // The glow is frozen at the same phase every run, so output is stable
+            for _ in 0..MAX_FRAMES {
+                if !offscreen.run(0.0, Vec::new(), |ctx| app.social_card(ctx, card, 0.0)) {
+                    break;
+                }
+            }
+            let path = options.out.join(card.file_name());
+            if let Err(err) = offscreen.image().save(&path) {
+                eprintln!("couldn't write {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+            println!("{}: {}", card.title(), path.display());
+        }
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,68 @@
+//! Print a static HTML snapshot of the page for crawlers and link previews
+//!
+//! ```text
+//! cargo run --bin snapshot -- [--part head|body|document] [--site-url URL]
+//!     [--asset-base BASE] [--og-image PATH] [--behind-canvas]
+//! ```
+//!
+//! `head` prints the meta, Open Graph, Twitter and JSON-LD tags for
+//! `index.html`'s `<head>`, `body` the page content to put in a `<noscript>`
+//! (or, with `--behind-canvas`, straight after the canvas), and `document`
+//! (the default) a standalone page with both.
+
+use std::process::ExitCode;
+
+use alexandria_landing::Snapshot;
+
+const USAGE: &str = "usage: snapshot [--part head|body|document] [--site-url URL] [--asset-base BASE] [--og-image PATH] [--behind-canvas]";
+
+enum Part {
+    Head,
+    Body,
+    Document,
+}
+
+fn parse(mut args: impl Iterator<Item = String>) -> Result<(Snapshot, Part), String> {
+    let mut snapshot = Snapshot::default();
+    let mut part = Part::Document;
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--part" => {
+                part = match value()?.as_str() {
+                    "head" => Part::Head,
+                    "body" => Part::Body,
+                    "document" => Part::Document,
+                    other => return Err(format!("unknown part {other:?}")),
+                }
+            }
+            "--site-url" => snapshot.site_url = value()?,
+            "--asset-base" => snapshot.asset_base = Some(value()?),
+            "--og-image" => snapshot.og_image = Some(value()?),
+            "--behind-canvas" => snapshot.behind_canvas = true,
+            other => return Err(format!("unknown argument {other:?}")),
+        }
+    }
+    Ok((snapshot, part))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<_> = std::env::args().skip(1).collect();
+    if args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    let (snapshot, part) = match parse(args.into_iter()) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{err}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match part {
+        Part::Head => println!("{}", snapshot.head()),
+        Part::Body => println!("{}", snapshot.body()),
+        Part::Document => print!("{}", snapshot.document()),
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,249 @@
+//! Frame profiler and debug overlay
+//!
+//! A HUD for diagnosing jank on real devices, toggled with F3 or opened with
+//! `?debug=1`. It graphs the interval between frames and the CPU time spent in
+//! `ui`, and lists the background layer's shape and vertex counts (recounted
+//! every half second or so), texture memory, the render quality, the active
+//! breakpoint and every hover tween that isn't at rest. Shift+F3 (or
+//! `?debug=outlines`) also outlines the card, metric and footer hit areas.
+//! While closed it only keeps the two timing windows.
+
+use std::collections::VecDeque;
+
+use egui::{epaint::Primitive, Color32, Key, Modifiers, Pos2, Rect, Stroke, Vec2};
+
+use crate::quality::Quality;
+use crate::tokens::Breakpoint;
+
+/// Frames kept for the graphs
+const HISTORY: usize = 120;
+
+/// Duration at the top of a graph (30 fps)
+const GRAPH_CEILING: f32 = 1.0 / 30.0;
+
+/// Duration marked on the graphs as the 60 fps budget
+const FRAME_BUDGET: f32 = 1.0 / 60.0;
+
+/// Frames between recounts of the background layer's shapes and vertices.
+/// Counting tessellates the layer again, so doing it every frame would about
+/// double the CPU time the HUD is there to measure.
+const STATS_INTERVAL: u32 = 30;
+
+/// Kinds of layout rect outlined when outlines are on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Outline {
+    Card,
+    Metric,
+    Footer,
+}
+
+impl Outline {
+    fn color(self) -> Color32 {
+        match self {
+            Outline::Card => Color32::from_rgb(0, 200, 255),
+            Outline::Metric => Color32::from_rgb(120, 255, 120),
+            Outline::Footer => Color32::from_rgb(255, 0, 200),
+        }
+    }
+}
+
+/// Rolling window of durations in seconds
+#[derive(Default)]
+struct Samples(VecDeque<f32>);
+
+impl Samples {
+    fn push(&mut self, value: f32) {
+        if self.0.len() == HISTORY {
+            self.0.pop_front();
+        }
+        self.0.push_back(value);
+    }
+
+    fn mean(&self) -> f32 {
+        if self.0.is_empty() {
+            return 0.0;
+        }
+        self.0.iter().sum::<f32>() / self.0.len() as f32
+    }
+
+    fn max(&self) -> f32 {
+        self.0.iter().copied().fold(0.0, f32::max)
+    }
+}
+
+/// App state shown in the HUD, gathered at the end of a frame
+pub(crate) struct Readout {
+    pub breakpoint: Breakpoint,
+    pub quality: Quality,
+    /// Tween name, element index and value for every tween not at rest
+    pub hover: Vec<(&'static str, usize, f32)>,
+}
+
+#[derive(Default)]
+pub(crate) struct DebugHud {
+    enabled: bool,
+    outlines: bool,
+    intervals: Samples,
+    cpu: Samples,
+    last_frame: Option<f64>,
+    rects: Vec<(Rect, Outline)>,
+    /// Last background layer shape and vertex counts, and frames since
+    stats: Option<(usize, usize)>,
+    stats_age: u32,
+}
+
+impl DebugHud {
+    /// HUD state for a `?debug=` query value
+    #[cfg(any(test, target_arch = "wasm32"))]
+    pub fn from_query(value: &str) -> Self {
+        let (enabled, outlines) = match value {
+            "outlines" => (true, true),
+            "1" | "true" | "on" => (true, false),
+            _ => (false, false),
+        };
+        Self { enabled, outlines, ..Self::default() }
+    }
+
+    /// Handle the shortcuts and record the interval since the previous frame
+    pub fn begin_frame(&mut self, ctx: &egui::Context, wall: f64) {
+        ctx.input_mut(|i| {
+            if i.consume_key(Modifiers::SHIFT, Key::F3) {
+                self.outlines = !self.outlines;
+                self.enabled |= self.outlines;
+            } else if i.consume_key(Modifiers::NONE, Key::F3) {
+                self.enabled = !self.enabled;
+            }
+        });
+        if let Some(last) = self.last_frame {
+            self.intervals.push((wall - last) as f32);
+        }
+        self.last_frame = Some(wall);
+        self.rects.clear();
+    }
+
+    /// Queue a layout rect to outline, if outlines are showing
+    pub fn outline(&mut self, rect: Rect, kind: Outline) {
+        if self.enabled && self.outlines {
+            self.rects.push((rect, kind));
+        }
+    }
+
+    /// Record this frame's `ui` CPU time and draw the HUD if it's open
+    pub fn end_frame(&mut self, ctx: &egui::Context, cpu: f64, readout: Readout) {
+        self.cpu.push(cpu as f32);
+        if !self.enabled {
+            self.stats = None;
+            return;
+        }
+
+        let painter = ctx.debug_painter();
+        for (rect, kind) in &self.rects {
+            painter.rect_stroke(*rect, 0.0, Stroke::new(1.0, kind.color()));
+        }
+
+        if self.stats.is_none() || self.stats_age >= STATS_INTERVAL {
+            self.stats = Some(background_stats(ctx));
+            self.stats_age = 0;
+        }
+        self.stats_age += 1;
+        let (shapes, vertices) = self.stats.unwrap_or_default();
+        let texture_bytes: usize = ctx.tex_manager().read().allocated().map(|(_, meta)| meta.bytes_used()).sum();
+        let interval = self.intervals.mean();
+        let mut lines = vec![
+            format!("{:>5.1} fps  interval {:>5.1} ms (max {:.1})", if interval > 0.0 { 1.0 / interval } else { 0.0 }, interval * 1000.0, self.intervals.max() * 1000.0),
+            format!("ui cpu {:>5.2} ms (max {:.2})", self.cpu.mean() * 1000.0, self.cpu.max() * 1000.0),
+            format!("background layer shapes {shapes}  vertices {vertices}"),
+            format!("textures {:.1} MiB", texture_bytes as f64 / (1024.0 * 1024.0)),
+            format!("breakpoint {}  quality {:?}", readout.breakpoint.name(), readout.quality),
+        ];
+        lines.extend(readout.hover.iter().map(|(name, idx, value)| format!("{name}[{idx}] {value:.3}")));
+
+        egui::Area::new(egui::Id::new("debug_hud"))
+            .order(egui::Order::Debug)
+            .fixed_pos(Pos2::new(8.0, 8.0))
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::none()
+                    .fill(Color32::from_black_alpha(220))
+                    .stroke(Stroke::new(1.0, Color32::from_gray(80)))
+                    .inner_margin(egui::Margin::same(8.0))
+                    .show(ui, |ui| {
+                        ui.spacing_mut().item_spacing.y = 4.0;
+                        graph(ui, "interval", &self.intervals, Color32::from_rgb(0, 200, 255));
+                        graph(ui, "ui cpu", &self.cpu, Color32::from_rgb(255, 160, 0));
+                        for line in lines {
+                            ui.label(egui::RichText::new(line).monospace().size(11.0).color(Color32::from_gray(220)));
+                        }
+                    });
+            });
+    }
+}
+
+/// Shape count and tessellated vertex count of the background layer, where
+/// the page draws. Tooltips and the foreground aren't counted.
+fn background_stats(ctx: &egui::Context) -> (usize, usize) {
+    let shapes: Vec<_> = ctx.graphics(|g| g.get(egui::LayerId::background()).map(|list| list.all_entries().cloned().collect()).unwrap_or_default());
+    let count = shapes.len();
+    let vertices = ctx
+        .tessellate(shapes, ctx.pixels_per_point())
+        .iter()
+        .map(|p| match &p.primitive {
+            Primitive::Mesh(mesh) => mesh.vertices.len(),
+            Primitive::Callback(_) => 0,
+        })
+        .sum();
+    (count, vertices)
+}
+
+/// Bar graph of a sample window, with the 60 fps budget marked
+fn graph(ui: &mut egui::Ui, label: &str, samples: &Samples, color: Color32) {
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(HISTORY as f32 * 2.0, 36.0), egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, Color32::from_gray(20));
+    for (i, value) in samples.0.iter().enumerate() {
+        let h = (value / GRAPH_CEILING).min(1.0) * rect.height();
+        let x = rect.left() + i as f32 * 2.0;
+        let bar_color = if *value > FRAME_BUDGET { Color32::from_rgb(255, 60, 60) } else { color };
+        painter.rect_filled(Rect::from_min_max(Pos2::new(x, rect.bottom() - h), Pos2::new(x + 1.5, rect.bottom())), 0.0, bar_color);
+    }
+    let budget_y = rect.bottom() - FRAME_BUDGET / GRAPH_CEILING * rect.height();
+    painter.hline(rect.x_range(), budget_y, Stroke::new(1.0, Color32::from_white_alpha(60)));
+    painter.text(rect.left_top() + Vec2::splat(2.0), egui::Align2::LEFT_TOP, label, egui::FontId::monospace(9.0), Color32::from_gray(160));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_keep_a_rolling_window() {
+        let mut s = Samples::default();
+        for i in 0..HISTORY + 10 {
+            s.push(i as f32);
+        }
+        assert_eq!(s.0.len(), HISTORY);
+        assert_eq!(s.0[0], 10.0);
+        assert_eq!(s.max(), (HISTORY + 9) as f32);
+        assert_eq!(Samples::default().mean(), 0.0);
+    }
+
+    #[test]
+    fn query_values() {
+        let hud = DebugHud::from_query("1");
+        assert!(hud.enabled && !hud.outlines);
+        let hud = DebugHud::from_query("outlines");
+        assert!(hud.enabled && hud.outlines);
+        assert!(!DebugHud::from_query("0").enabled);
+    }
+
+    #[test]
+    fn outlines_are_only_collected_when_shown() {
+        let rect = Rect::from_min_size(Pos2::ZERO, Vec2::splat(10.0));
+        let mut hud = DebugHud::from_query("1");
+        hud.outline(rect, Outline::Card);
+        assert!(hud.rects.is_empty());
+        let mut hud = DebugHud::from_query("outlines");
+        hud.outline(rect, Outline::Card);
+        assert_eq!(hud.rects, vec![(rect, Outline::Card)]);
+    }
+}
@@ -0,0 +1,401 @@
+//! The page as semantic HTML
+//!
+//! [`page`] builds the content the canvas draws (hero, metrics, product cards,
+//! footer) as a small element tree with a stylesheet derived from a
+//! [`ColorPalette`]. In the browser it's turned into real DOM nodes when the
+//! canvas can't start, so visitors without WebGL still get the whole page with
+//! proper headings, lists and links for screen readers and find-in-page.
+//! Natively it's serialized to HTML for the static snapshot in
+//! [`crate::snapshot`].
+
+use std::fmt::Write;
+
+use egui::Color32;
+
+use crate::assets::AssetResolver;
+use crate::glossary::{self, Segment, GLOSSARY};
+use crate::markup::{self, SpanKind};
+use crate::{ColorPalette, FooterGroup, COPYRIGHT_TEXT, FOOTER_LINKS, METRICS, PRODUCTS, SUBTITLE_TEXT, TYPEWRITER_WORDS};
+
+/// Class on the root element that every style rule is scoped under
+const ROOT_CLASS: &str = "alexandria-page";
+
+/// Elements written without a closing tag
+const VOID_ELEMENTS: &[&str] = &["br", "img", "link", "meta"];
+
+/// Elements whose text is written unescaped
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
+pub(crate) enum Node {
+    Element(Element),
+    Text(String),
+}
+
+pub(crate) struct Element {
+    pub tag: &'static str,
+    pub attrs: Vec<(&'static str, String)>,
+    pub children: Vec<Node>,
+}
+
+impl Element {
+    pub fn new(tag: &'static str) -> Self {
+        Self { tag, attrs: Vec::new(), children: Vec::new() }
+    }
+
+    pub fn attr(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.attrs.push((name, value.into()));
+        self
+    }
+
+    pub fn class(self, class: &'static str) -> Self {
+        self.attr("class", class)
+    }
+
+    pub fn child(mut self, child: impl Into<Node>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    pub fn children<N: Into<Node>>(mut self, children: impl IntoIterator<Item = N>) -> Self {
+        self.children.extend(children.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.child(Node::Text(text.into()))
+    }
+
+    /// Serialize as HTML
+    pub fn to_html(&self) -> String {
+        let mut out = String::new();
+        self.write_html(&mut out);
+        out
+    }
+
+    fn write_html(&self, out: &mut String) {
+        let _ = write!(out, "<{}", self.tag);
+        for (name, value) in &self.attrs {
+            let _ = write!(out, " {name}=\"{}\"", escape(value));
+        }
+        out.push('>');
+        if VOID_ELEMENTS.contains(&self.tag) {
+            return;
+        }
+        for child in &self.children {
+            match child {
+                Node::Element(e) => e.write_html(out),
+                Node::Text(t) if RAW_TEXT_ELEMENTS.contains(&self.tag) => out.push_str(t),
+                Node::Text(t) => out.push_str(&escape(t)),
+            }
+        }
+        let _ = write!(out, "</{}>", self.tag);
+    }
+
+    /// Build this element and its children as DOM nodes
+    #[cfg(target_arch = "wasm32")]
+    pub fn to_dom(&self, doc: &web_sys::Document) -> Result<web_sys::Element, wasm_bindgen::JsValue> {
+        let el = doc.create_element(self.tag)?;
+        for (name, value) in &self.attrs {
+            el.set_attribute(name, value)?;
+        }
+        for child in &self.children {
+            let node: web_sys::Node = match child {
+                Node::Element(e) => e.to_dom(doc)?.into(),
+                Node::Text(t) => doc.create_text_node(t).into(),
+            };
+            el.append_child(&node)?;
+        }
+        Ok(el)
+    }
+}
+
+impl From<Element> for Node {
+    fn from(e: Element) -> Self {
+        Node::Element(e)
+    }
+}
+
+impl From<&str> for Node {
+    fn from(t: &str) -> Self {
+        Node::Text(t.to_owned())
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Product copy with its inline markup stripped
+pub(crate) fn plain(text: &str) -> String {
+    markup::parse(text).iter().map(|span| span.text).collect()
+}
+
+/// CSS color for a palette color
+pub(crate) fn css(color: Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    format!("rgba({r},{g},{b},{:.3})", a as f32 / 255.0)
+}
+
+fn stylesheet(colors: &ColorPalette) -> String {
+    let mono = "ui-monospace,SFMono-Regular,Menlo,monospace";
+    let label = format!("font:12px {mono};text-transform:uppercase;letter-spacing:.06em");
+    let rules: Vec<(&str, String)> = vec![
+        ("", format!("position:fixed;inset:0;overflow:auto;background:{};color:{};font:16px/1.5 system-ui,-apple-system,sans-serif", css(colors.bg_primary), css(colors.text_primary))),
+        ("> *", "max-width:1100px;margin:0 auto;padding:0 16px".into()),
+        ("a", "color:inherit".into()),
+        ("> header", "text-align:center;padding-top:40px".into()),
+        ("h1", "font-size:clamp(28px,5vw,48px);margin:0".into()),
+        (".subtitle", format!("color:{};margin:8px 0", css(colors.text_secondary))),
+        (".capabilities", format!("color:{};{label};margin:0 0 32px", css(colors.text_muted))),
+        (".notice", format!("border-left:3px solid {};background:{};padding:12px 16px;margin:0 0 32px;text-align:left", css(colors.accent_rust), css(colors.tag_bg))),
+        (".notice details", format!("color:{};font-size:13px", css(colors.text_muted))),
+        (".metrics", format!("display:grid;grid-template-columns:repeat(auto-fit,minmax(120px,1fr));border:solid {};border-width:1px 0;margin:0;padding:20px 0;text-align:center", css(colors.border))),
+        (".metrics div", "display:flex;flex-direction:column-reverse".into()),
+        (".metrics dd", format!("margin:0;font:28px/1.2 {mono}")),
+        (".metrics dt", format!("color:{};{label}", css(colors.text_muted))),
+        (".products", "display:grid;grid-template-columns:repeat(auto-fill,minmax(300px,1fr));gap:24px;padding:32px 0".into()),
+        ("article", format!("border-bottom:1px solid {};padding-bottom:16px", css(colors.border))),
+        (".card-head", "display:flex;gap:12px;align-items:center".into()),
+        ("article img", "width:48px;height:48px;border-radius:4px".into()),
+        ("h3", "margin:0;font-size:20px".into()),
+        ("h3 a", "text-decoration:none".into()),
+        (".tags, .links, footer ul", "list-style:none;display:flex;flex-wrap:wrap;padding:0".into()),
+        (".tags", "gap:6px;margin:4px 0 0".into()),
+        (".tags li", format!("background:{};color:{};{label};font-size:11px;padding:2px 5px;border-radius:3px", css(colors.tag_bg), css(colors.tag_text))),
+        (".tagline", "margin:12px 0 8px".into()),
+        (".description", format!("color:{};font-size:14px", css(colors.text_secondary))),
+        (".token", format!("color:{};background:{};font:inherit", css(colors.accent_ember), css(colors.tag_bg))),
+        ("abbr", format!("text-decoration:underline {};cursor:help", css(colors.accent_copper))),
+        (".links", "gap:16px;font-size:14px".into()),
+        (".links a, .description a", format!("color:{}", css(colors.accent_rust))),
+        ("footer nav", "display:grid;grid-template-columns:repeat(auto-fit,minmax(200px,1fr));gap:24px;text-align:center".into()),
+        ("footer h2", format!("color:{};{label};margin:0", css(colors.text_muted))),
+        ("footer ul", "justify-content:center;gap:12px".into()),
+        (".copyright", format!("color:{};text-align:center;font-size:13px;padding:24px 0 60px", css(colors.text_muted))),
+        (".visually-hidden", "position:absolute;width:1px;height:1px;overflow:hidden;clip:rect(0 0 0 0);white-space:nowrap".into()),
+    ];
+    rules
+        .iter()
+        .map(|(selector, body)| {
+            let scoped: Vec<_> = selector.split(", ").map(|s| format!(".{ROOT_CLASS} {s}").trim_end().to_owned()).collect();
+            format!("{}{{{body}}}", scoped.join(","))
+        })
+        .collect()
+}
+
+/// Product copy with its inline markup and glossary terms as elements
+fn rich(text: &str) -> Vec<Node> {
+    markup::parse(text)
+        .into_iter()
+        .flat_map(|span| {
+            let mut nodes: Vec<Node> = match span.kind {
+                SpanKind::Text => glossary::segments(span.text)
+                    .into_iter()
+                    .map(|segment| match segment {
+                        Segment::Plain(s) => s.into(),
+                        Segment::Term(s, idx) => Element::new("abbr").attr("title", GLOSSARY[idx].definition).text(s).into(),
+                    })
+                    .collect(),
+                SpanKind::Token => vec![Element::new("code").class("token").text(span.text).into()],
+                SpanKind::Link(url) => vec![Element::new("a").attr("href", url).text(span.text).into()],
+            };
+            if span.italic {
+                nodes = vec![Element::new("em").children(nodes).into()];
+            }
+            if span.bold {
+                nodes = vec![Element::new("strong").children(nodes).into()];
+            }
+            nodes
+        })
+        .collect()
+}
+
+/// Section heading for screen readers only
+fn hidden_heading(id: &'static str, text: &str) -> Element {
+    Element::new("h2").attr("id", id).class("visually-hidden").text(text)
+}
+
+/// The whole page, with an optional `notice` under the hero
+pub(crate) fn page(colors: &ColorPalette, assets: &AssetResolver, notice: Option<Element>) -> Element {
+    let hero = Element::new("header")
+        .child(Element::new("h1").text("Alexandria"))
+        .child(Element::new("p").class("subtitle").text(SUBTITLE_TEXT))
+        .child(Element::new("p").class("capabilities").text(TYPEWRITER_WORDS.join(" · ")))
+        .children(notice);
+
+    let metrics = Element::new("section")
+        .attr("aria-labelledby", "metrics-heading")
+        .child(hidden_heading("metrics-heading", "Metrics"))
+        .child(Element::new("dl").class("metrics").children(METRICS.iter().map(|m| {
+            Element::new("div").child(Element::new("dt").text(m.label)).child(Element::new("dd").text(m.value))
+        })));
+
+    let cards = PRODUCTS.iter().map(|p| {
+        let logo = p.image.preferred(48.0).next().map(|path| Element::new("img").attr("src", assets.resolve(path)).attr("alt", "").attr("width", "48").attr("height", "48"));
+        let head = Element::new("div")
+            .class("card-head")
+            .children(logo)
+            .child(Element::new("div")
+                .child(Element::new("h3").child(Element::new("a").attr("href", p.primary_url()).text(p.name)))
+                .child(Element::new("ul").class("tags").attr("aria-label", "Tags").children(p.tags.iter().map(|t| Element::new("li").text(*t)))));
+        let links = Element::new("ul").class("links").children(p.links.iter().map(|l| {
+            Element::new("li").child(Element::new("a").attr("href", l.url).text(l.kind.label()))
+        }));
+        Element::new("article")
+            .child(head)
+            .child(Element::new("p").class("tagline").children(rich(p.tagline)))
+            .child(Element::new("p").class("description").children(rich(p.description)))
+            .child(Element::new("nav").attr("aria-label", format!("{} links", p.name)).child(links))
+    });
+    let products = Element::new("section")
+        .attr("aria-labelledby", "products-heading")
+        .child(hidden_heading("products-heading", "Products"))
+        .child(Element::new("div").class("products").children(cards));
+
+    let groups = FooterGroup::ALL.iter().filter_map(|&group| {
+        let links: Vec<_> = FOOTER_LINKS.iter().filter(|l| l.group == group).collect();
+        (!links.is_empty()).then(|| {
+            Element::new("section")
+                .child(Element::new("h2").text(group.label()))
+                .child(Element::new("ul").children(links.iter().map(|l| Element::new("li").child(Element::new("a").attr("href", l.url).text(l.title)))))
+        })
+    });
+    let footer = Element::new("footer")
+        .child(Element::new("nav").attr("aria-label", "Alexandria elsewhere").children(groups))
+        .child(Element::new("p").class("copyright").text(COPYRIGHT_TEXT));
+
+    Element::new("div")
+        .class(ROOT_CLASS)
+        .child(Element::new("style").text(stylesheet(colors)))
+        .child(hero)
+        .child(Element::new("main").child(metrics).child(products))
+        .child(footer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all<'a>(e: &'a Element, tag: &str, out: &mut Vec<&'a Element>) {
+        if e.tag == tag {
+            out.push(e);
+        }
+        for child in &e.children {
+            if let Node::Element(c) = child {
+                all(c, tag, out);
+            }
+        }
+    }
+
+    fn find<'a>(e: &'a Element, tag: &str) -> Vec<&'a Element> {
+        let mut out = Vec::new();
+        all(e, tag, &mut out);
+        out
+    }
+
+    fn attr<'a>(e: &'a Element, name: &str) -> Option<&'a str> {
+        e.attrs.iter().find(|(n, _)| *n == name).map(|(_, v)| v.as_str())
+    }
+
+    fn text(e: &Element) -> String {
+        e.children.iter().map(|c| match c {
+            Node::Text(t) => t.clone(),
+            Node::Element(e) => text(e),
+        }).collect()
+    }
+
+    fn test_page() -> Element {
+        page(&ColorPalette::default(), &AssetResolver::new("https://lbry.app/", None), None)
+    }
+
+    #[test]
+    fn links_every_product_and_footer_link() {
+        let page = test_page();
+        let hrefs: Vec<_> = find(&page, "a").into_iter().filter_map(|a| attr(a, "href")).collect();
+        for p in PRODUCTS {
+            for link in p.links {
+                assert!(hrefs.contains(&link.url), "{} missing {}", p.name, link.url);
+            }
+        }
+        for link in FOOTER_LINKS {
+            assert!(hrefs.contains(&link.url), "footer missing {}", link.url);
+        }
+    }
+
+    #[test]
+    fn one_card_and_heading_per_product() {
+        let page = test_page();
+        assert_eq!(find(&page, "article").len(), PRODUCTS.len());
+        let names: Vec<_> = find(&page, "h3").into_iter().map(text).collect();
+        assert_eq!(names, PRODUCTS.iter().map(|p| p.name).collect::<Vec<_>>());
+        assert_eq!(find(&page, "h1").len(), 1);
+    }
+
+    #[test]
+    fn metrics_are_term_value_pairs() {
+        let page = test_page();
+        let labels: Vec<_> = find(&page, "dt").into_iter().map(text).collect();
+        let values: Vec<_> = find(&page, "dd").into_iter().map(text).collect();
+        assert_eq!(labels, METRICS.iter().map(|m| m.label).collect::<Vec<_>>());
+        assert_eq!(values, METRICS.iter().map(|m| m.value).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn copy_markup_becomes_elements() {
+        let p = Element::new("p").children(rich("Home of $ALEX. **100% of revenue** stored on ArWeave, see [docs](https://x.org)."));
+        assert_eq!(text(&p), "Home of $ALEX. 100% of revenue stored on ArWeave, see docs.");
+        assert_eq!(text(find(&p, "code")[0]), "$ALEX");
+        assert_eq!(text(find(&p, "strong")[0]), "100% of revenue");
+        assert_eq!(attr(find(&p, "abbr")[0], "title"), Some(GLOSSARY[0].definition));
+        assert_eq!(attr(find(&p, "a")[0], "href"), Some("https://x.org"));
+    }
+
+    #[test]
+    fn logos_resolve_against_the_asset_base() {
+        let page = test_page();
+        let src = attr(find(&page, "img")[0], "src").unwrap();
+        assert!(src.starts_with("https://lbry.app/logos/"), "{src}");
+    }
+
+    #[test]
+    fn styles_are_scoped_and_use_the_palette() {
+        let css_text = stylesheet(&ColorPalette::default());
+        assert!(css_text.contains(&css(ColorPalette::default().accent_rust)));
+        for rule in css_text.split('}').filter(|r| !r.is_empty()) {
+            let selectors = rule.split('{').next().unwrap();
+            assert!(selectors.split(',').all(|s| s.starts_with(&format!(".{ROOT_CLASS}"))), "{selectors}");
+        }
+    }
+
+    #[test]
+    fn serializes_escaped_html() {
+        let e = Element::new("p")
+            .attr("title", "\"quoted\" & <b>")
+            .text("Tom & Jerry's <tag>")
+            .child(Element::new("img").attr("src", "a.png"))
+            .child(Element::new("style").text(".a > b{}"));
+        assert_eq!(e.to_html(), "<p title=\"&quot;quoted&quot; &amp; &lt;b&gt;\">Tom &amp; Jerry&#39;s &lt;tag&gt;<img src=\"a.png\"><style>.a > b{}</style></p>");
+    }
+
+    #[test]
+    fn plain_copy_drops_markup() {
+        assert_eq!(plain("Home of $ALEX. **100% of revenue**, see [docs](https://x.org)."), "Home of $ALEX. 100% of revenue, see docs.");
+    }
+
+    #[test]
+    fn css_colors_are_unmultiplied() {
+        assert_eq!(css(Color32::from_rgb(247, 76, 0)), "rgba(247,76,0,1.000)");
+        assert_eq!(css(Color32::from_rgba_unmultiplied(255, 255, 255, 51)), "rgba(255,255,255,0.200)");
+    }
+}
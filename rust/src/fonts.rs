@@ -0,0 +1,200 @@
+//! Brand typefaces
+//!
+//! The display face (Space Grotesk, as on the TypeScript site) sets the hero and
+//! product names; the mono face (Space Mono) sets tags and metrics. Both are
+//! fetched from `/fonts/` through egui's bytes loaders and swapped in once they
+//! arrive. Until then, and natively where there is no HTTP loader, the named
+//! families fall back to egui's bundled Ubuntu and Hack faces so text never
+//! references a family that isn't bound.
+
+use egui::{FontData, FontDefinitions, FontFamily};
+
+use crate::assets::AssetResolver;
+use crate::tokens::{DISPLAY_FAMILY, MONO_FAMILY};
+
+/// A font file that is prepended to one of the named families once loaded
+struct BrandFace {
+    key: &'static str,
+    family: &'static str,
+    path: &'static str,
+}
+
+const BRAND_FACES: &[BrandFace] = &[
+    BrandFace { key: "SpaceGrotesk-Medium", family: DISPLAY_FAMILY, path: "/fonts/SpaceGrotesk-Medium.ttf" },
+    BrandFace { key: "SpaceMono-Regular", family: MONO_FAMILY, path: "/fonts/SpaceMono-Regular.ttf" },
+];
+
+/// Whether `bytes` start like a TrueType or OpenType font. Static hosts answer
+/// a missing file with the page's `index.html`, and egui panics on font data it
+/// can't parse.
+fn is_font(bytes: &[u8]) -> bool {
+    [b"\x00\x01\x00\x00", b"OTTO", b"true"].iter().any(|magic| bytes.starts_with(*magic))
+}
+
+/// Site paths of the brand faces that aren't usable fonts under `dir`, for
+/// native tools that read assets from disk
+#[cfg(not(target_arch = "wasm32"))]
+pub fn missing_faces(dir: &std::path::Path) -> Vec<&'static str> {
+    BRAND_FACES
+        .iter()
+        .map(|face| face.path)
+        .filter(|path| !std::fs::read(dir.join(path.trim_start_matches('/'))).is_ok_and(|bytes| is_font(&bytes)))
+        .collect()
+}
+
+/// egui's default fonts plus the named families, with any loaded brand faces first
+fn definitions(loaded: &[(usize, FontData)]) -> FontDefinitions {
+    let mut fonts = FontDefinitions::default();
+    let fallback = |fonts: &FontDefinitions, family| fonts.families.get(&family).cloned().unwrap_or_default();
+    let mut display = fallback(&fonts, FontFamily::Proportional);
+    let mut mono = fallback(&fonts, FontFamily::Monospace);
+
+    for (idx, data) in loaded {
+        let face = &BRAND_FACES[*idx];
+        fonts.font_data.insert(face.key.to_owned(), data.clone());
+        let list = if face.family == DISPLAY_FAMILY { &mut display } else { &mut mono };
+        list.insert(0, face.key.to_owned());
+    }
+
+    fonts.families.insert(FontFamily::Name(DISPLAY_FAMILY.into()), display);
+    fonts.families.insert(FontFamily::Name(MONO_FAMILY.into()), mono);
+    fonts
+}
+
+/// Tracks which brand faces have arrived and reinstalls fonts when one does
+#[derive(Default)]
+pub(crate) struct BrandFonts {
+    loaded: Vec<(usize, FontData)>,
+    /// Faces that are loaded or have failed, and need no more polling
+    settled: Vec<usize>,
+    /// Number of installs so far, so text caches know when to start over
+    generation: u32,
+}
+
+impl BrandFonts {
+    /// Install the named families with fallback faces. Takes effect next frame.
+    pub fn install(&mut self, ctx: &egui::Context) {
+        ctx.set_fonts(definitions(&self.loaded));
+        self.generation += 1;
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// Whether the named families are bound in the current frame's fonts
+    pub fn ready(ctx: &egui::Context) -> bool {
+        let families = ctx.fonts(|f| f.families());
+        [DISPLAY_FAMILY, MONO_FAMILY].iter().all(|name| families.contains(&FontFamily::Name((*name).into())))
+    }
+
+    /// Request faces that failed to load again, after the asset base changes.
+    /// (Logo failures are per URL, so a new base starts them afresh anyway.)
+    #[cfg(any(test, target_arch = "wasm32"))]
+    pub fn retry_failed(&mut self) {
+        let loaded = &self.loaded;
+        self.settled.retain(|idx| loaded.iter().any(|(i, _)| i == idx));
+    }
+
+    /// Poll the loaders for brand faces still in flight
+    pub fn poll(&mut self, ctx: &egui::Context, assets: &AssetResolver) {
+        let mut changed = false;
+        for (idx, face) in BRAND_FACES.iter().enumerate() {
+            if self.settled.contains(&idx) {
+                continue;
+            }
+            let uri = assets.resolve(face.path);
+            match ctx.try_load_bytes(&uri) {
+                Ok(egui::load::BytesPoll::Ready { bytes, .. }) if !is_font(&bytes) => {
+                    log::warn!("font {uri} isn't a TrueType or OpenType file, using fallback");
+                    self.settled.push(idx);
+                }
+                Ok(egui::load::BytesPoll::Ready { bytes, .. }) => {
+                    self.loaded.push((idx, FontData::from_owned(bytes.to_vec())));
+                    self.settled.push(idx);
+                    changed = true;
+                }
+                Ok(egui::load::BytesPoll::Pending { .. }) => {}
+                Err(err) => {
+                    log::warn!("font {uri} unavailable, using fallback: {err}");
+                    self.settled.push(idx);
+                }
+            }
+        }
+        if changed {
+            self.install(ctx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_families_fall_back_to_defaults() {
+        let fonts = definitions(&[]);
+        let defaults = FontDefinitions::default();
+        assert_eq!(fonts.families[&FontFamily::Name(DISPLAY_FAMILY.into())], defaults.families[&FontFamily::Proportional]);
+        assert_eq!(fonts.families[&FontFamily::Name(MONO_FAMILY.into())], defaults.families[&FontFamily::Monospace]);
+    }
+
+    #[test]
+    fn loaded_faces_take_priority() {
+        let data = FontDefinitions::default().font_data["Hack"].clone();
+        let fonts = definitions(&[(0, data)]);
+        assert_eq!(fonts.families[&FontFamily::Name(DISPLAY_FAMILY.into())][0], BRAND_FACES[0].key);
+        assert!(fonts.font_data.contains_key(BRAND_FACES[0].key));
+        assert_ne!(fonts.families[&FontFamily::Name(MONO_FAMILY.into())][0], BRAND_FACES[0].key);
+    }
+
+    #[test]
+    fn missing_and_invalid_faces_are_reported() {
+        let dir = std::env::temp_dir().join(format!("alexandria-fonts-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("fonts")).unwrap();
+        std::fs::write(dir.join(BRAND_FACES[0].path.trim_start_matches('/')), b"\x00\x01\x00\x00rest of the font").unwrap();
+        assert_eq!(missing_faces(&dir), vec![BRAND_FACES[1].path]);
+        std::fs::write(dir.join(BRAND_FACES[0].path.trim_start_matches('/')), b"<!DOCTYPE html>").unwrap();
+        assert_eq!(missing_faces(&dir).len(), BRAND_FACES.len());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn html_served_for_a_font_is_skipped() {
+        let ctx = egui::Context::default();
+        let assets = AssetResolver::new("https://example.com/", None);
+        for face in BRAND_FACES {
+            ctx.include_bytes(assets.resolve(face.path), b"<!DOCTYPE html><html></html>".as_slice());
+        }
+        let mut fonts = BrandFonts::default();
+        fonts.poll(&ctx, &assets);
+        assert!(fonts.loaded.is_empty());
+        assert_eq!(fonts.settled.len(), BRAND_FACES.len());
+        assert_eq!(fonts.generation(), 0);
+
+        let hack = FontDefinitions::default().font_data["Hack"].font.clone();
+        assert!(is_font(&hack));
+        assert!(is_font(b"OTTO\x00\x0a"));
+    }
+
+    #[test]
+    fn failed_faces_are_retried_from_a_new_base() {
+        let ctx = egui::Context::default();
+        let mut fonts = BrandFonts::default();
+        // Nothing is served from the first base
+        fonts.poll(&ctx, &AssetResolver::new("https://example.com/", None));
+        assert_eq!(fonts.settled.len(), BRAND_FACES.len());
+
+        let cdn = AssetResolver::new("https://example.com/", Some("https://cdn.example.com/"));
+        let hack = FontDefinitions::default().font_data["Hack"].font.clone();
+        for face in BRAND_FACES {
+            ctx.include_bytes(cdn.resolve(face.path), hack.to_vec());
+        }
+        fonts.poll(&ctx, &cdn);
+        assert!(fonts.loaded.is_empty(), "failed faces stay settled until retried");
+        fonts.retry_failed();
+        fonts.poll(&ctx, &cdn);
+        assert_eq!(fonts.loaded.len(), BRAND_FACES.len());
+        assert_eq!(fonts.generation(), 1);
+    }
+}
@@ -0,0 +1,194 @@
+//! Per-frame-invariant text layout
+//!
+//! Most text on the page is the same from one frame to the next: tags, metric
+//! values, footer labels, the hero at a given typewriter step, and the product
+//! copy, whose markup and glossary scan are the most expensive part of laying
+//! out a frame. [`GalleyCache`] keeps the laid-out galleys keyed by what they
+//! were built from, so a frame only lays out text that actually changed.
+//! Everything is dropped when the viewport width, pixels-per-point, font scale,
+//! installed fonts or palette change, since those either change how every key
+//! lays out or leave the old entries unreachable.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use egui::{text::LayoutJob, Color32, FontFamily, FontId, Galley, TextFormat};
+
+use crate::tokens::Typography;
+use crate::{ColorPalette, HotspotKind};
+
+/// Sections of a rich galley that are hotspot targets, by section index
+pub(crate) type Targets = Arc<[(u32, HotspotKind)]>;
+
+/// Entries kept before the cache starts over, bounding growth from text that
+/// keeps changing (the typewriter walks through a few hundred prefixes)
+const MAX_ENTRIES: usize = 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Style {
+    Plain,
+    /// Uppercased with letter spacing, as section and tag labels are set
+    Label,
+    /// Wrapped copy with inline markup, laid out by the caller
+    Rich,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Key {
+    text: String,
+    /// Font size's bits, since `FontId` isn't `Eq`
+    size: u32,
+    family: FontFamily,
+    color: Color32,
+    /// Wrap width's bits, infinity's for single-line text
+    width: u32,
+    style: Style,
+}
+
+impl Key {
+    fn new(text: &str, font: FontId, color: Color32, style: Style) -> Self {
+        Self { text: text.to_owned(), size: font.size.to_bits(), family: font.family, color, width: f32::INFINITY.to_bits(), style }
+    }
+
+    fn font(&self) -> FontId {
+        FontId::new(f32::from_bits(self.size), self.family.clone())
+    }
+}
+
+/// What every cached layout depends on besides its own key. Wrapped copy is
+/// keyed by its wrap width already, but a resize changes every wrap width, so
+/// that's when stale entries would pile up.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Environment {
+    pub width: f32,
+    pub pixels_per_point: f32,
+    pub font_scale: f32,
+    /// Bumped each time fonts are reinstalled
+    pub fonts: u32,
+    pub palette: ColorPalette,
+}
+
+#[derive(Default)]
+pub(crate) struct GalleyCache {
+    env: Environment,
+    galleys: HashMap<Key, (Arc<Galley>, Targets)>,
+}
+
+impl GalleyCache {
+    /// Start a frame, dropping every galley if the environment changed
+    pub fn begin_frame(&mut self, env: Environment) {
+        if env != self.env || self.galleys.len() > MAX_ENTRIES {
+            self.galleys.clear();
+            self.env = env;
+        }
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.galleys.len()
+    }
+
+    /// Single-line text, as `Fonts::layout_no_wrap`
+    pub fn no_wrap(&mut self, ui: &egui::Ui, text: &str, font: FontId, color: Color32) -> Arc<Galley> {
+        self.get(ui, Key::new(text, font, color, Style::Plain))
+    }
+
+    /// Uppercase label with letter spacing for the mono tag/metric style
+    pub fn label(&mut self, ui: &egui::Ui, text: &str, font: FontId, color: Color32) -> Arc<Galley> {
+        self.get(ui, Key::new(text, font, color, Style::Label))
+    }
+
+    /// Copy wrapped at `width`, with the layout job and its hotspot targets
+    /// built by `build` only when they aren't cached
+    pub fn rich(&mut self, ui: &egui::Ui, text: &str, font: FontId, color: Color32, width: f32, build: impl FnOnce() -> (LayoutJob, Vec<(u32, HotspotKind)>)) -> (Arc<Galley>, Targets) {
+        let key = Key { width: width.to_bits(), ..Key::new(text, font, color, Style::Rich) };
+        self.entry(key, |_| {
+            let (job, targets) = build();
+            (ui.fonts(|f| f.layout_job(job)), targets.into())
+        })
+    }
+
+    fn get(&mut self, ui: &egui::Ui, key: Key) -> Arc<Galley> {
+        self.entry(key, |key| (ui.fonts(|f| f.layout_job(single_line_job(key))), Arc::new([]))).0
+    }
+
+    fn entry(&mut self, key: Key, layout: impl FnOnce(&Key) -> (Arc<Galley>, Targets)) -> (Arc<Galley>, Targets) {
+        self.galleys.entry(key).or_insert_with_key(layout).clone()
+    }
+}
+
+fn single_line_job(key: &Key) -> LayoutJob {
+    let font = key.font();
+    if key.style == Style::Label {
+        let format = TextFormat { extra_letter_spacing: Typography::label_spacing(&font), ..TextFormat::simple(font, key.color) };
+        LayoutJob::single_section(key.text.to_uppercase(), format)
+    } else {
+        LayoutJob::single_section(key.text.clone(), TextFormat::simple(font, key.color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(width: f32) -> Environment {
+        Environment { width, pixels_per_point: 1.0, font_scale: 1.0, fonts: 0, palette: ColorPalette::default() }
+    }
+
+    /// Run `f` inside a frame of a fresh context
+    fn with_ui(f: impl FnOnce(&egui::Ui)) {
+        let ctx = egui::Context::default();
+        let mut f = Some(f);
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| (f.take().unwrap())(ui));
+        });
+    }
+
+    #[test]
+    fn repeated_text_is_laid_out_once() {
+        with_ui(|ui| {
+            let mut cache = GalleyCache::default();
+            cache.begin_frame(env(800.0));
+            let font = FontId::proportional(14.0);
+            let a = cache.no_wrap(ui, "Alexandria", font.clone(), Color32::WHITE);
+            let b = cache.no_wrap(ui, "Alexandria", font.clone(), Color32::WHITE);
+            assert!(Arc::ptr_eq(&a, &b));
+            // Any part of the key makes a different galley
+            cache.no_wrap(ui, "Alexandria", font.clone(), Color32::RED);
+            cache.label(ui, "Alexandria", font, Color32::WHITE);
+            assert_eq!(cache.len(), 3);
+        });
+    }
+
+    #[test]
+    fn labels_are_uppercase_and_tracked() {
+        with_ui(|ui| {
+            let mut cache = GalleyCache::default();
+            let font = FontId::monospace(12.0);
+            let label = cache.label(ui, "defi", font.clone(), Color32::WHITE);
+            let plain = cache.no_wrap(ui, "DEFI", font, Color32::WHITE);
+            assert_eq!(label.text(), "DEFI");
+            assert!(label.size().x > plain.size().x);
+        });
+    }
+
+    #[test]
+    fn environment_changes_invalidate() {
+        with_ui(|ui| {
+            let mut cache = GalleyCache::default();
+            cache.begin_frame(env(800.0));
+            cache.no_wrap(ui, "a", FontId::default(), Color32::WHITE);
+            cache.begin_frame(env(800.0));
+            assert_eq!(cache.len(), 1);
+            cache.begin_frame(env(600.0));
+            assert_eq!(cache.len(), 0);
+            cache.no_wrap(ui, "a", FontId::default(), Color32::WHITE);
+            cache.begin_frame(Environment { fonts: 1, ..env(600.0) });
+            assert_eq!(cache.len(), 0);
+            cache.no_wrap(ui, "a", FontId::default(), Color32::WHITE);
+            let palette = ColorPalette { text_primary: Color32::LIGHT_GRAY, ..ColorPalette::default() };
+            cache.begin_frame(Environment { fonts: 1, palette, ..env(600.0) });
+            assert_eq!(cache.len(), 0);
+        });
+    }
+}
@@ -0,0 +1,153 @@
+//! Glossary of ICP and DeFi jargon that appears in product descriptions
+
+pub(crate) struct GlossaryTerm {
+    pub term: &'static str,
+    /// Lowercase phrases that mark this term in running text
+    pub aliases: &'static [&'static str],
+    pub definition: &'static str,
+}
+
+pub(crate) const GLOSSARY: &[GlossaryTerm] = &[
+    GlossaryTerm {
+        term: "ArWeave",
+        aliases: &["arweave"],
+        definition: "A blockchain for permanent storage: data is paid for once and replicated across the network indefinitely.",
+    },
+    GlossaryTerm {
+        term: "ckBTC",
+        aliases: &["ckbtc"],
+        definition: "Chain-key Bitcoin. A 1:1 BTC-backed token on ICP, held by canisters that sign Bitcoin transactions directly with threshold ECDSA.",
+    },
+    GlossaryTerm {
+        term: "VRF",
+        aliases: &["vrf"],
+        definition: "Verifiable Random Function. ICP subnets produce randomness that no single node can predict or bias, and anyone can verify.",
+    },
+    GlossaryTerm {
+        term: "Blackholed Canister",
+        aliases: &["blackholed canisters", "blackholed canister", "blackholed"],
+        definition: "A canister whose controllers have been removed, so its code can never be upgraded or changed by anyone.",
+    },
+    GlossaryTerm {
+        term: "LP Tokens",
+        aliases: &["lp tokens"],
+        definition: "Liquidity provider tokens. Receipts for assets deposited into a DEX pool, redeemable for a share of that pool.",
+    },
+    GlossaryTerm {
+        term: "Wyoming DAO LLC",
+        aliases: &["wyoming dao llc"],
+        definition: "A US legal entity recognised under Wyoming law whose management can be carried out by a smart contract.",
+    },
+    GlossaryTerm {
+        term: "HTTP Outcalls",
+        aliases: &["http outcalls"],
+        definition: "Lets ICP canisters call external web APIs directly, with responses agreed on by the subnet's nodes.",
+    },
+];
+
+/// A run of text that is either plain or a recognised glossary term
+pub(crate) enum Segment<'a> {
+    Plain(&'a str),
+    /// Matched text and its index into [`GLOSSARY`]
+    Term(&'a str, usize),
+}
+
+/// Split text into plain runs and glossary terms, preferring the longest
+/// alias at each word boundary
+pub(crate) fn segments(text: &str) -> Vec<Segment<'_>> {
+    let is_word = |c: char| c.is_alphanumeric();
+    let mut out = Vec::new();
+    let mut plain_start = 0;
+    let mut prev: Option<char> = None;
+
+    let mut iter = text.char_indices().peekable();
+    while let Some((i, c)) = iter.next() {
+        let at_word_start = is_word(c) && !prev.is_some_and(is_word);
+        prev = Some(c);
+        if !at_word_start {
+            continue;
+        }
+
+        let best = GLOSSARY
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, t)| t.aliases.iter().map(move |alias| (idx, *alias)))
+            .filter(|(_, alias)| {
+                text.get(i..i + alias.len()).is_some_and(|s| s.eq_ignore_ascii_case(alias))
+                    && !text[i + alias.len()..].chars().next().is_some_and(is_word)
+            })
+            .max_by_key(|(_, alias)| alias.len())
+            .map(|(idx, alias)| (idx, alias.len()));
+
+        if let Some((idx, len)) = best {
+            if plain_start < i {
+                out.push(Segment::Plain(&text[plain_start..i]));
+            }
+            out.push(Segment::Term(&text[i..i + len], idx));
+            plain_start = i + len;
+            // Skip past the matched term
+            while iter.peek().is_some_and(|&(j, _)| j < plain_start) {
+                prev = iter.next().map(|(_, c)| c);
+            }
+        }
+    }
+
+    if plain_start < text.len() {
+        out.push(Segment::Plain(&text[plain_start..]));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Each segment's text, with the glossary index for terms
+    fn split(text: &str) -> Vec<(&str, Option<usize>)> {
+        segments(text)
+            .into_iter()
+            .map(|s| match s {
+                Segment::Plain(text) => (text, None),
+                Segment::Term(text, idx) => (text, Some(idx)),
+            })
+            .collect()
+    }
+
+    fn index(term: &str) -> usize {
+        GLOSSARY.iter().position(|t| t.term == term).unwrap()
+    }
+
+    #[test]
+    fn longest_alias_wins() {
+        let blackholed = Some(index("Blackholed Canister"));
+        assert_eq!(split("uses blackholed canisters only"), vec![("uses ", None), ("blackholed canisters", blackholed), (" only", None)]);
+        assert_eq!(split("a blackholed canister"), vec![("a ", None), ("blackholed canister", blackholed)]);
+        assert_eq!(split("blackholed forever"), vec![("blackholed", blackholed), (" forever", None)]);
+    }
+
+    #[test]
+    fn terms_match_whole_words() {
+        assert_eq!(split("arweaves"), vec![("arweaves", None)]);
+        assert_eq!(split("prearweave"), vec![("prearweave", None)]);
+        assert_eq!(split("vrf2"), vec![("vrf2", None)]);
+        assert_eq!(split("(VRF)."), vec![("(", None), ("VRF", Some(index("VRF"))), (").", None)]);
+    }
+
+    #[test]
+    fn matching_ignores_case_but_keeps_it() {
+        let ckbtc = Some(index("ckBTC"));
+        assert_eq!(split("Pay in ckBTC or CKBTC"), vec![("Pay in ", None), ("ckBTC", ckbtc), (" or ", None), ("CKBTC", ckbtc)]);
+    }
+
+    #[test]
+    fn non_ascii_neighbours_are_safe() {
+        let arweave = Some(index("ArWeave"));
+        assert_eq!(split("stored—ArWeave—forever"), vec![("stored—", None), ("ArWeave", arweave), ("—forever", None)]);
+        // Letters outside ASCII still count as part of a word
+        assert_eq!(split("éarweave"), vec![("éarweave", None)]);
+        assert_eq!(split("arweaveé"), vec![("arweaveé", None)]);
+        // An alias's length can end mid-character in the text
+        assert_eq!(split("vr€"), vec![("vr€", None)]);
+        assert_eq!(split("ckbt€ ckbtc"), vec![("ckbt€ ", None), ("ckbtc", Some(index("ckBTC")))]);
+    }
+}
@@ -0,0 +1,357 @@
+//! Vector icons drawn from a compact SVG path subset
+//!
+//! Paths use a 24×24 view box and support `M`, `L`, `Q`, `C` and `Z` (plus
+//! their lowercase relative forms). Curves are flattened in screen space at the
+//! current `pixels_per_point`, so icons stay crisp at any zoom. Icons are
+//! stroked outlines in the same weight as the rest of the page's line art.
+//!
+//! The path data is adapted from [Lucide](https://lucide.dev), used under the
+//! ISC License; its notice is in `NOTICE`.
+
+use egui::epaint::{CubicBezierShape, QuadraticBezierShape};
+use egui::{Color32, Painter, Pos2, Shape, Stroke, Vec2};
+use std::sync::OnceLock;
+
+/// Side of the square view box icon paths are authored in
+const VIEW_BOX: f32 = 24.0;
+
+/// Stroke width in view box units
+const STROKE_WIDTH: f32 = 2.0;
+
+/// Built-in icons
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Icon {
+    X,
+    Github,
+    Document,
+    ShieldCheck,
+    ExternalLink,
+    Candlestick,
+    Swap,
+    Coin,
+    Crown,
+    Dashboard,
+}
+
+impl Icon {
+    /// Every icon, in declaration order
+    const ALL: [Icon; 10] = [
+        Icon::X,
+        Icon::Github,
+        Icon::Document,
+        Icon::ShieldCheck,
+        Icon::ExternalLink,
+        Icon::Candlestick,
+        Icon::Swap,
+        Icon::Coin,
+        Icon::Crown,
+        Icon::Dashboard,
+    ];
+
+    /// Path data in the view box
+    fn path(self) -> &'static str {
+        match self {
+            Icon::X => "M4 4 L15.6 20 L20 20 L8.4 4 Z M4 20 L10.6 13.4 M13.4 10.6 L20 4",
+            Icon::Github => {
+                "M15 22 L15 18 C15 16.6 14.6 15.3 14 14.5 C17 14.5 20 12.5 20 9 C20.08 7.75 19.73 6.52 19 5.5 \
+                 C19.28 4.35 19.28 3.15 19 2 C19 2 18 2 16 3.5 C13.36 3 10.64 3 8 3.5 C6 2 5 2 5 2 \
+                 C4.7 3.15 4.7 4.35 5 5.5 C4.35 6.5 4 7.8 4 9 C4 12.5 7 14.5 10 14.5 \
+                 C9.61 14.99 9.32 15.55 9.15 16.15 C8.98 16.75 8.93 17.38 9 18 L9 22 \
+                 M9 18 C4.49 20 4 16 2 16"
+            }
+            Icon::Document => {
+                "M14 2 L6 2 Q4 2 4 4 L4 20 Q4 22 6 22 L18 22 Q20 22 20 20 L20 8 Z M14 2 L14 8 L20 8 \
+                 M16 13 L8 13 M16 17 L8 17 M10 9 L8 9"
+            }
+            Icon::ShieldCheck => {
+                "M20 13 C20 18 16.5 20.5 12.34 21.95 Q12 22.06 11.67 21.94 C7.5 20.5 4 18 4 13 L4 6 Q4 5 5 5 \
+                 C7 5 9.5 3.8 11.24 2.28 Q12 1.7 12.76 2.28 C14.51 3.81 17 5 19 5 Q20 5 20 6 Z \
+                 M9 12 L11 14 L15 10"
+            }
+            Icon::ExternalLink => "M15 3 L21 3 L21 9 M10 14 L21 3 M18 13 L18 19 Q18 21 16 21 L5 21 Q3 21 3 19 L3 8 Q3 6 5 6 L11 6",
+            Icon::Candlestick => {
+                "M9 5 L9 9 M7 9 L11 9 L11 15 L7 15 Z M9 15 L9 17 M17 3 L17 5 M15 5 L19 5 L19 13 L15 13 Z \
+                 M17 13 L17 16 M3 3 L3 19 Q3 21 5 21 L21 21"
+            }
+            Icon::Swap => "M8 3 L4 7 L8 11 M4 7 L20 7 M16 21 L20 17 L16 13 M20 17 L4 17",
+            Icon::Coin => {
+                "M22 12 C22 17.52 17.52 22 12 22 C6.48 22 2 17.52 2 12 C2 6.48 6.48 2 12 2 C17.52 2 22 6.48 22 12 Z \
+                 M18 12 C18 15.31 15.31 18 12 18 C8.69 18 6 15.31 6 12 C6 8.69 8.69 6 12 6 C15.31 6 18 8.69 18 12 Z"
+            }
+            Icon::Crown => "M3 18 L5 7 L9.5 11 L12 5 L14.5 11 L19 7 L21 18 Z M3 21 L21 21",
+            Icon::Dashboard => "M3 3 L10 3 L10 12 L3 12 Z M14 3 L21 3 L21 8 L14 8 Z M14 12 L21 12 L21 21 L14 21 Z M3 16 L10 16 L10 21 L3 21 Z",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum PathCmd {
+    MoveTo(Pos2),
+    LineTo(Pos2),
+    QuadTo(Pos2, Pos2),
+    CubicTo(Pos2, Pos2, Pos2),
+    Close,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct ParseError {
+    /// Byte offset into the path data
+    pub offset: usize,
+    pub message: &'static str,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+/// Parse path data into absolute commands
+pub(crate) fn parse(d: &str) -> Result<Vec<PathCmd>, ParseError> {
+    let mut lexer = Lexer { src: d, pos: 0 };
+    let mut cmds = Vec::new();
+    let mut current = Pos2::ZERO;
+    let mut subpath_start = Pos2::ZERO;
+    let mut command: Option<u8> = None;
+
+    loop {
+        lexer.skip_separators();
+        let Some(&c) = d.as_bytes().get(lexer.pos) else { break };
+        if c.is_ascii_alphabetic() {
+            if !b"MLQCZmlqcz".contains(&c) {
+                return Err(lexer.error("unsupported command"));
+            }
+            lexer.pos += 1;
+            command = Some(c);
+        } else if command.is_none() {
+            return Err(lexer.error("expected a command"));
+        }
+
+        let cmd = command.unwrap_or(b'M');
+        let origin = if cmd.is_ascii_lowercase() { current.to_vec2() } else { Vec2::ZERO };
+        match cmd.to_ascii_uppercase() {
+            b'M' => {
+                current = lexer.point()? + origin;
+                subpath_start = current;
+                cmds.push(PathCmd::MoveTo(current));
+                // Coordinates after a move are implicit line-tos
+                command = Some(if cmd == b'm' { b'l' } else { b'L' });
+            }
+            b'L' => {
+                current = lexer.point()? + origin;
+                cmds.push(PathCmd::LineTo(current));
+            }
+            b'Q' => {
+                let ctrl = lexer.point()? + origin;
+                current = lexer.point()? + origin;
+                cmds.push(PathCmd::QuadTo(ctrl, current));
+            }
+            b'C' => {
+                let c1 = lexer.point()? + origin;
+                let c2 = lexer.point()? + origin;
+                current = lexer.point()? + origin;
+                cmds.push(PathCmd::CubicTo(c1, c2, current));
+            }
+            _ => {
+                cmds.push(PathCmd::Close);
+                current = subpath_start;
+                // `Z` takes no arguments, so anything but a command after it is an error
+                command = None;
+            }
+        }
+        if !matches!(cmds.first(), Some(PathCmd::MoveTo(_))) {
+            return Err(ParseError { offset: 0, message: "path must start with a move" });
+        }
+    }
+    Ok(cmds)
+}
+
+struct Lexer<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl Lexer<'_> {
+    fn error(&self, message: &'static str) -> ParseError {
+        ParseError { offset: self.pos, message }
+    }
+
+    fn skip_separators(&mut self) {
+        let rest = &self.src.as_bytes()[self.pos..];
+        self.pos += rest.iter().take_while(|c| c.is_ascii_whitespace() || **c == b',').count();
+    }
+
+    fn number(&mut self) -> Result<f32, ParseError> {
+        self.skip_separators();
+        let bytes = self.src.as_bytes();
+        let start = self.pos;
+        let mut end = start;
+        if matches!(bytes.get(end), Some(b'-' | b'+')) {
+            end += 1;
+        }
+        let mut seen_dot = false;
+        while let Some(&c) = bytes.get(end) {
+            match c {
+                b'0'..=b'9' => {}
+                // A second dot starts the next number, as in "1.5.5"
+                b'.' if !seen_dot => seen_dot = true,
+                _ => break,
+            }
+            end += 1;
+        }
+        let value = self.src[start..end].parse().map_err(|_| self.error("expected a number"))?;
+        self.pos = end;
+        Ok(value)
+    }
+
+    fn point(&mut self) -> Result<Pos2, ParseError> {
+        Ok(Pos2::new(self.number()?, self.number()?))
+    }
+}
+
+/// Built-in icons parsed once on first use, indexed by `Icon`. Paths that fail
+/// to parse draw nothing; the `builtin_icons_parse` test keeps that from
+/// happening.
+fn builtin(icon: Icon) -> &'static [PathCmd] {
+    static ICON_CACHE: OnceLock<Vec<Vec<PathCmd>>> = OnceLock::new();
+    let cache = ICON_CACHE.get_or_init(|| Icon::ALL.iter().map(|icon| parse(icon.path()).unwrap_or_default()).collect());
+    &cache[icon as usize]
+}
+
+/// Stroke a built-in icon centered on `center` and `size` points wide
+pub(crate) fn paint(painter: &Painter, icon: Icon, center: Pos2, size: f32, color: Color32) {
+    let cmds = builtin(icon);
+
+    let scale = size / VIEW_BOX;
+    let origin = center - Vec2::splat(size / 2.0);
+    let to_screen = |p: Pos2| origin + p.to_vec2() * scale;
+    let stroke = Stroke::new(STROKE_WIDTH * scale, color);
+    // Flatten to within a tenth of a physical pixel
+    let tolerance = 0.1 / painter.ctx().pixels_per_point();
+
+    for (points, closed) in flatten(cmds, to_screen, tolerance) {
+        if points.len() < 2 {
+            continue;
+        }
+        painter.add(if closed { Shape::closed_line(points, stroke) } else { Shape::line(points, stroke) });
+    }
+}
+
+/// Flatten commands into polylines, each flagged as closed or open
+fn flatten(cmds: &[PathCmd], to_screen: impl Fn(Pos2) -> Pos2, tolerance: f32) -> Vec<(Vec<Pos2>, bool)> {
+    let mut lines = Vec::new();
+    let mut points: Vec<Pos2> = Vec::new();
+    for cmd in cmds {
+        let last = points.last().copied().unwrap_or(Pos2::ZERO);
+        match *cmd {
+            PathCmd::MoveTo(p) => {
+                if points.len() > 1 {
+                    lines.push((std::mem::take(&mut points), false));
+                }
+                points.clear();
+                points.push(to_screen(p));
+            }
+            PathCmd::LineTo(p) => points.push(to_screen(p)),
+            PathCmd::QuadTo(c, p) => {
+                let curve = QuadraticBezierShape::from_points_stroke([last, to_screen(c), to_screen(p)], false, Color32::TRANSPARENT, Stroke::NONE);
+                points.extend(curve.flatten(Some(tolerance)).into_iter().skip(1));
+            }
+            PathCmd::CubicTo(c1, c2, p) => {
+                let curve = CubicBezierShape::from_points_stroke([last, to_screen(c1), to_screen(c2), to_screen(p)], false, Color32::TRANSPARENT, Stroke::NONE);
+                points.extend(curve.flatten(Some(tolerance)).into_iter().skip(1));
+            }
+            PathCmd::Close => {
+                // Drop a final point that duplicates the start; the closed line joins them
+                if points.len() > 1 && points.first().zip(points.last()).is_some_and(|(a, b)| a.distance(*b) < 1e-3) {
+                    points.pop();
+                }
+                let start = points.first().copied();
+                lines.push((std::mem::take(&mut points), true));
+                // Drawing may continue from the start of the closed subpath
+                points.extend(start);
+            }
+        }
+    }
+    if points.len() > 1 {
+        lines.push((points, false));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: f32, y: f32) -> Pos2 {
+        Pos2::new(x, y)
+    }
+
+    #[test]
+    fn builtin_icons_parse() {
+        for (idx, icon) in Icon::ALL.into_iter().enumerate() {
+            assert_eq!(icon as usize, idx, "{icon:?} is out of order in Icon::ALL");
+            assert!(parse(icon.path()).is_ok(), "{icon:?}: {:?}", parse(icon.path()));
+        }
+    }
+
+    #[test]
+    fn absolute_commands() {
+        assert_eq!(
+            parse("M1 2 L3 4 Q5 6 7 8 C9 10 11 12 13 14 Z").unwrap(),
+            vec![
+                PathCmd::MoveTo(p(1.0, 2.0)),
+                PathCmd::LineTo(p(3.0, 4.0)),
+                PathCmd::QuadTo(p(5.0, 6.0), p(7.0, 8.0)),
+                PathCmd::CubicTo(p(9.0, 10.0), p(11.0, 12.0), p(13.0, 14.0)),
+                PathCmd::Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn relative_commands_and_implicit_lines() {
+        assert_eq!(
+            parse("m1,1 2,0 l0 2 z l1 1").unwrap(),
+            vec![
+                PathCmd::MoveTo(p(1.0, 1.0)),
+                PathCmd::LineTo(p(3.0, 1.0)),
+                PathCmd::LineTo(p(3.0, 3.0)),
+                PathCmd::Close,
+                // Relative to the closed subpath's start
+                PathCmd::LineTo(p(2.0, 2.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn compact_numbers() {
+        assert_eq!(
+            parse("M-.5.5L1.5-2").unwrap(),
+            vec![PathCmd::MoveTo(p(-0.5, 0.5)), PathCmd::LineTo(p(1.5, -2.0))]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse("L1 1").unwrap_err().message, "path must start with a move");
+        assert_eq!(parse("1 1").unwrap_err().message, "expected a command");
+        assert_eq!(parse("M0 0 A1 1 0 0 0 2 2").unwrap_err(), ParseError { offset: 5, message: "unsupported command" });
+        assert_eq!(parse("M0 0 L1").unwrap_err().message, "expected a number");
+        assert_eq!(parse("M0 0 Z 1 1").unwrap_err(), ParseError { offset: 7, message: "expected a command" });
+    }
+
+    #[test]
+    fn flatten_splits_subpaths() {
+        let cmds = parse("M0 0 L1 0 L1 1 Z M5 5 L6 6").unwrap();
+        let lines = flatten(&cmds, |p| p, 0.1);
+        assert_eq!(lines, vec![(vec![p(0.0, 0.0), p(1.0, 0.0), p(1.0, 1.0)], true), (vec![p(5.0, 5.0), p(6.0, 6.0)], false)]);
+    }
+
+    #[test]
+    fn flatten_curves_end_on_their_endpoint() {
+        let cmds = parse("M0 0 C0 10 10 10 10 0").unwrap();
+        let (points, _) = &flatten(&cmds, |p| p, 0.01)[0];
+        assert!(points.len() > 4);
+        assert_eq!(points.last(), Some(&p(10.0, 0.0)));
+    }
+}
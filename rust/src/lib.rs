@@ -1,11 +1,53 @@
 //! Alexandria Landing Page - Pure Rust Canvas Rendering
-
-#![cfg(target_arch = "wasm32")]
-
-use eframe::egui::{self, Color32, FontFamily, FontId, Pos2, Rect, Rounding, Sense, Stroke, Vec2};
+//!
+//! The page itself is plain egui and builds on any target; only the browser
+//! glue (clock, navigation, `WebRunner` startup) is wasm32-specific. That keeps
+//! `cargo test` running the markup, layout and asset logic on the host instead
+//! of needing a browser.
+
+use egui::{self, text::LayoutJob, Color32, FontFamily, FontId, Pos2, Rect, Rounding, Sense, Stroke, TextFormat, Vec2};
+#[cfg(target_arch = "wasm32")]
 use wasm_bindgen::{prelude::wasm_bindgen, JsCast};
 
-#[derive(Clone, Copy)]
+mod assets;
+mod debug;
+mod document;
+mod fonts;
+mod galleys;
+mod glossary;
+mod icons;
+mod logging;
+mod logos;
+mod markup;
+#[cfg(not(target_arch = "wasm32"))]
+mod offscreen;
+mod quality;
+mod schedule;
+#[cfg(not(target_arch = "wasm32"))]
+mod script;
+mod snapshot;
+#[cfg(not(target_arch = "wasm32"))]
+mod social;
+// Only the browser build has a startup to fail; native builds compile it for its tests
+#[cfg(any(test, target_arch = "wasm32"))]
+mod startup;
+mod tokens;
+mod visibility;
+
+use assets::AssetResolver;
+use debug::{DebugHud, Outline, Readout};
+use fonts::BrandFonts;
+use galleys::{Environment, GalleyCache};
+use glossary::{Segment, GLOSSARY};
+use icons::Icon;
+use logos::{ImageVariants, LogoLoader, LogoState};
+use quality::QualityGovernor;
+use schedule::{approach, next_tick, RepaintSchedule};
+use markup::SpanKind;
+use tokens::{Breakpoint, Typography};
+use visibility::Visibility;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 struct ColorPalette {
     bg_primary: Color32,
     text_primary: Color32,
@@ -38,10 +80,14 @@ impl Default for ColorPalette {
     }
 }
 
-/// Interpolate between two colors
+/// Interpolate between two colors, component-wise on their premultiplied
+/// values. Between opaque colors, as in every glow, that's the same as mixing
+/// unmultiplied values; with a translucent end like `text_muted` it keeps the
+/// brightness, where treating premultiplied values as unmultiplied dimmed it
+/// twice.
 fn lerp_color(a: Color32, b: Color32, t: f32) -> Color32 {
     let t = t.clamp(0.0, 1.0);
-    Color32::from_rgba_unmultiplied(
+    Color32::from_rgba_premultiplied(
         (a.r() as f32 + (b.r() as f32 - a.r() as f32) * t) as u8,
         (a.g() as f32 + (b.g() as f32 - a.g() as f32) * t) as u8,
         (a.b() as f32 + (b.b() as f32 - a.b() as f32) * t) as u8,
@@ -57,7 +103,11 @@ struct HoverState {
     /// Scanline position for each hovered product (0.0 to 1.0)
     scanline_pos: std::collections::HashMap<usize, f32>,
     /// Metric hover intensities
-    metric_hover: [f32; 4],
+    metric_hover: std::collections::HashMap<usize, f32>,
+    /// Maps footer link index to hover intensity
+    footer_hover: std::collections::HashMap<usize, f32>,
+    /// Set when any tween moved this frame, cleared by `take_animating`
+    animating: bool,
 }
 
 impl HoverState {
@@ -66,8 +116,7 @@ impl HoverState {
         let intensity = self.product_hover.entry(idx).or_insert(0.0);
         let target = if is_hovered { 1.0 } else { 0.0 };
         let speed = 8.0; // Animation speed
-        *intensity += (target - *intensity) * speed * dt;
-        *intensity = intensity.clamp(0.0, 1.0);
+        self.animating |= approach(intensity, target, speed, dt);
         *intensity
     }
 
@@ -75,6 +124,7 @@ impl HoverState {
     fn update_scanline(&mut self, idx: usize, is_hovered: bool, dt: f32) -> f32 {
         let pos = self.scanline_pos.entry(idx).or_insert(0.0);
         if is_hovered {
+            self.animating = true;
             *pos += dt * 0.8; // Scanline speed
             if *pos > 1.3 {
                 *pos = -0.3; // Reset with buffer for smooth loop
@@ -85,36 +135,37 @@ impl HoverState {
         *pos
     }
 
+    /// Whether any tween moved since the last call
+    fn take_animating(&mut self) -> bool {
+        std::mem::take(&mut self.animating)
+    }
+
+    /// Every intensity that isn't at rest, for the debug HUD
+    fn active(&self) -> Vec<(&'static str, usize, f32)> {
+        let mut active: Vec<_> = [("product", &self.product_hover), ("metric", &self.metric_hover), ("footer", &self.footer_hover)]
+            .into_iter()
+            .flat_map(|(name, map)| map.iter().filter(|(_, v)| **v > 0.0).map(move |(idx, v)| (name, *idx, *v)))
+            .collect();
+        active.sort_by_key(|(name, idx, _)| (*name, *idx));
+        active
+    }
+
     /// Update metric hover
     fn update_metric(&mut self, idx: usize, is_hovered: bool, dt: f32) -> f32 {
-        if idx >= 4 { return 0.0; }
+        let intensity = self.metric_hover.entry(idx).or_insert(0.0);
         let target = if is_hovered { 1.0 } else { 0.0 };
         let speed = 10.0;
-        self.metric_hover[idx] += (target - self.metric_hover[idx]) * speed * dt;
-        self.metric_hover[idx].clamp(0.0, 1.0)
+        self.animating |= approach(intensity, target, speed, dt);
+        *intensity
     }
-}
 
-struct Typography {
-    title_size: f32,
-    subtitle_size: f32,
-    metric_value_size: f32,
-    metric_label_size: f32,
-    product_name_size: f32,
-    tagline_size: f32,
-    description_size: f32,
-    tag_size: f32,
-}
-
-impl Typography {
-    fn for_width(width: f32) -> Self {
-        if width < 480.0 {
-            Self { title_size: 28.0, subtitle_size: 14.0, metric_value_size: 18.0, metric_label_size: 9.0, product_name_size: 15.0, tagline_size: 12.0, description_size: 11.0, tag_size: 8.0 }
-        } else if width < 768.0 {
-            Self { title_size: 36.0, subtitle_size: 16.0, metric_value_size: 22.0, metric_label_size: 10.0, product_name_size: 16.0, tagline_size: 13.0, description_size: 12.0, tag_size: 9.0 }
-        } else {
-            Self { title_size: 52.0, subtitle_size: 20.0, metric_value_size: 26.0, metric_label_size: 11.0, product_name_size: 18.0, tagline_size: 14.0, description_size: 13.0, tag_size: 9.0 }
-        }
+    /// Update footer icon hover
+    fn update_footer(&mut self, idx: usize, is_hovered: bool, dt: f32) -> f32 {
+        let intensity = self.footer_hover.entry(idx).or_insert(0.0);
+        let target = if is_hovered { 1.0 } else { 0.0 };
+        let speed = 10.0;
+        self.animating |= approach(intensity, target, speed, dt);
+        *intensity
     }
 }
 
@@ -123,32 +174,127 @@ struct Product {
     name: &'static str,
     tagline: &'static str,
     description: &'static str,
-    url: &'static str,
+    /// Shown left to right in the card's action bar
+    links: &'static [ProductLink],
     tags: &'static [&'static str],
-    image: &'static str,
+    image: ImageVariants,
+}
+
+impl Product {
+    /// The link opened by clicking anywhere on the card: the app, if it has one
+    fn primary_url(&self) -> &'static str {
+        self.links.iter()
+            .find(|l| l.kind == LinkKind::App)
+            .or(self.links.first())
+            .map_or("", |l| l.url)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum LinkKind {
+    App,
+    Docs,
+    Source,
+    Dashboard,
+    Social,
+}
+
+impl LinkKind {
+    fn label(self) -> &'static str {
+        match self {
+            LinkKind::App => "Open App",
+            LinkKind::Docs => "Docs",
+            LinkKind::Source => "Source",
+            LinkKind::Dashboard => "Canister Dashboard",
+            LinkKind::Social => "Social",
+        }
+    }
+
+    /// Built-in icon for this kind of link
+    fn icon(self) -> Icon {
+        match self {
+            LinkKind::App => Icon::ExternalLink,
+            LinkKind::Docs => Icon::Document,
+            LinkKind::Source => Icon::Github,
+            LinkKind::Dashboard => Icon::Dashboard,
+            LinkKind::Social => Icon::X,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ProductLink { kind: LinkKind, url: &'static str }
+
+/// An interactive region inside a product card. These are registered after the
+/// card's own click sense so they sit on top of it in egui's hit test.
+struct Hotspot {
+    rect: Rect,
+    id: egui::Id,
+    kind: HotspotKind,
+}
+
+#[derive(Clone)]
+enum HotspotKind {
+    /// Index into `GLOSSARY`
+    Glossary(usize),
+    /// Inline link target, opened independently of the card's own URL
+    Link(String),
+    /// Icon in the card's action bar
+    Action(ProductLink),
 }
 
 #[derive(Clone)]
 struct Metric { value: &'static str, label: &'static str }
 
+/// Labeled section of the footer
+#[derive(Clone, Copy, PartialEq)]
+enum FooterGroup {
+    Community,
+    Docs,
+    Markets,
+}
+
+impl FooterGroup {
+    /// Sections in the order they appear
+    const ALL: [FooterGroup; 3] = [FooterGroup::Community, FooterGroup::Docs, FooterGroup::Markets];
+
+    fn label(self) -> &'static str {
+        match self {
+            FooterGroup::Community => "Community",
+            FooterGroup::Docs => "Docs",
+            FooterGroup::Markets => "Markets",
+        }
+    }
+}
+
 #[derive(Clone)]
-struct FooterLink { url: &'static str, icon: FooterIcon, title: &'static str }
+struct FooterLink {
+    group: FooterGroup,
+    url: &'static str,
+    /// Name of the built-in vector icon, also drawn while `image` loads
+    icon: Icon,
+    /// Logo image relative to the site root, for external services
+    image: Option<&'static str>,
+    title: &'static str,
+}
 
-#[derive(Clone, Copy)]
-enum FooterIcon {
-    Twitter,
-    Github,
-    Whitepaper,
-    Audit,
-    // Image-based icons (external exchanges/services)
-    KongSwap,
-    IcpSwap,
-    DexScreener,
-    IcpTokens,
+/// A hoverable element on the page, by index into its list
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Target {
+    Product(usize),
+    Metric(usize),
+    Footer(usize),
 }
 
+/// Where logos and fonts are served from: relative to the page, an absolute URL
+/// or `ic://<canister-id>/`. `None` serves them next to the page. The
+/// `asset-base` meta tag and `set_asset_base` take precedence.
+pub(crate) const ASSET_BASE: Option<&str> = None;
+
 const SUBTITLE_TEXT: &str = "Building ICP's Great City";
 
+const COPYRIGHT_TEXT: &str = "© 2025 Alexandria. Open source, fair launched, and owned by its stakers.";
+
 const TYPEWRITER_WORDS: &[&str] = &[
     "VetKey Encryption",
     "Chain-Key ECDSA",
@@ -247,64 +393,90 @@ impl TypewriterState {
         // Blink cursor every 0.5 seconds
         ((time * 2.0) as i32) % 2 == 0
     }
+
+    /// When the text or cursor next changes
+    fn next_wakeup(&self, time: f64) -> f64 {
+        let step = if self.is_deleting { 0.05 } else { 0.1 };
+        let next_char = if time < self.pause_until { self.pause_until } else { self.last_update + step };
+        next_char.min(next_tick(time, 0.5))
+    }
 }
 
 const PRODUCTS: &[Product] = &[
     Product {
         name: "lbry.app",
-        tagline: "The library and city center of Alexandria. Home of the $ALEX token—100% of revenue from all projects returns to stakers here.",
-        description: "LBRY.app is a decentralized archival platform for permanently preserving Web2 content as revenue-sharing NFTs. Built on ArWeave for permanent storage and ICP for compute, content creators mint NFTs that earn ongoing ALEX rewards whenever users interact with the network. The dual-token economy features LBRY as mintable operational currency and ALEX (hard-capped at 21M) for governance and staking revenue—with two-thirds of total supply distributed to NFT creators and content owners.",
-        url: "https://lbry.app",
+        tagline: "The library and city center of Alexandria. Home of the $ALEX token—**100% of revenue** from all projects returns to stakers here.",
+        description: "LBRY.app is a decentralized archival platform for permanently preserving Web2 content as revenue-sharing NFTs. Built on ArWeave for permanent storage and ICP for compute, content creators mint NFTs that earn ongoing $ALEX rewards whenever users interact with the network. The dual-token economy features $LBRY as mintable operational currency and $ALEX (hard-capped at 21M) for governance and staking revenue—with two-thirds of total supply distributed to NFT creators and content owners.",
+        links: &[
+            ProductLink { kind: LinkKind::App, url: "https://lbry.app" },
+            ProductLink { kind: LinkKind::Docs, url: "https://lbry.app/info/whitepaper" },
+            ProductLink { kind: LinkKind::Source, url: "https://github.com/AlexandriaDAO/alexandria" },
+            ProductLink { kind: LinkKind::Social, url: "https://x.com/alexandria_lbry" },
+        ],
         tags: &["DeFi", "ContentFi", "Infrastructure"],
-        image: "/logos/projects/alex.png",
+        image: ImageVariants::png("/logos/projects/alex.png", 256).with_2x("/logos/projects/high-res/alex.png"),
     },
     Product {
         name: "CycleScan",
         tagline: "The cycle burn leaderboard for ICP. Like CoinGecko, but for cycle consumption.",
         description: "CycleScan tracks real-time cycle consumption across the Internet Computer, monitoring ~2,900 canisters from 100+ projects. Features include 7-day burn rate trends with sparkline visualizations, top-up detection with inferred burn calculations, and project-level aggregation. Data updates hourly via GitHub Actions with no backend canister required—a fully static architecture that fetches live data directly from raw GitHub URLs.",
-        url: "https://xknwi-uaaaa-aaaak-qu4oq-cai.icp0.io",
+        links: &[
+            ProductLink { kind: LinkKind::App, url: "https://xknwi-uaaaa-aaaak-qu4oq-cai.icp0.io" },
+            ProductLink { kind: LinkKind::Dashboard, url: "https://dashboard.internetcomputer.org/canister/xknwi-uaaaa-aaaak-qu4oq-cai" },
+        ],
         tags: &["Infrastructure", "Analytics"],
-        image: "/logos/projects/cyclescan.png",
+        image: ImageVariants::png("/logos/projects/cyclescan.png", 200),
     },
     Product {
         name: "IPG",
         tagline: "The first fully decentralized payment gateway. Accept BTC and ICP with zero setup fees, no KYC, and sub-second payment detection.",
-        description: "Internet Payment Gateway is a first-of-its-kind merchant solution uniquely possible on ICP—leveraging HTTP outcalls for real-time browser-based payment polling and chain-key ckBTC for trustless Bitcoin settlement. No intermediaries, no monthly fees, no KYC. Just copy-paste a widget into any website and start accepting crypto. Regressive withdrawal fees (1.0% → 0.2%) reward volume, and all funds remain fully self-custodial until you withdraw.",
-        url: "https://62rey-3aaaa-aaaag-acjla-cai.icp0.io",
+        description: "Internet Payment Gateway is a first-of-its-kind merchant solution uniquely possible on ICP—leveraging HTTP outcalls for real-time browser-based payment polling and chain-key ckBTC for trustless Bitcoin settlement. *No intermediaries, no monthly fees, no KYC.* Just copy-paste a widget into any website and start accepting crypto. Regressive withdrawal fees (1.0% → 0.2%) reward volume, and all funds remain fully self-custodial until you withdraw.",
+        links: &[
+            ProductLink { kind: LinkKind::App, url: "https://62rey-3aaaa-aaaag-acjla-cai.icp0.io" },
+            ProductLink { kind: LinkKind::Dashboard, url: "https://dashboard.internetcomputer.org/canister/62rey-3aaaa-aaaag-acjla-cai" },
+        ],
         tags: &["Payments", "Infrastructure", "DeFi"],
-        image: "/logos/projects/ipg.png",
+        image: ImageVariants::png("/logos/projects/ipg.png", 258),
     },
     Product {
         name: "OpenHouse",
         tagline: "A decentralized casino where anyone can be the house, using ICP's unique randomness for skill-based and prediction market gaming.",
-        description: "OpenHouse is a provably fair casino where you can be the house. Users provide liquidity and earn from a transparent 1% house edge, with all randomness cryptographically verified via Internet Computer's VRF. Games include Crash, Plinko, Blackjack, and Dice—all open-source with publicly auditable odds. 100% of platform revenue flows to ALEX stakers.",
-        url: "https://openhouse.games",
+        description: "OpenHouse is a provably fair casino where *you* can be the house. Users provide liquidity and earn from a transparent 1% house edge, with all randomness cryptographically verified via Internet Computer's VRF. Games include Crash, Plinko, Blackjack, and Dice—all open-source with publicly auditable odds. **100% of platform revenue** flows to $ALEX stakers.",
+        links: &[
+            ProductLink { kind: LinkKind::App, url: "https://openhouse.games" },
+        ],
         tags: &["Gaming", "DeFi"],
-        image: "/logos/projects/openhouse.png",
+        image: ImageVariants::png("/logos/projects/openhouse.png", 1024).with_2x("/logos/projects/high-res/openhouse.png"),
     },
     Product {
         name: "DAOPad",
         tagline: "Turning LLCs into Smart Contracts.",
-        description: "DAOPad turns LLCs into Smart Contracts, unifying company structure, legal agreements, and operations into a single autonomous system. Winner of DFINITY's WCHL2025 Hackathon (Real-World-Asset Track), it provides a white-glove framework for migrating to a legally recognized Wyoming DAO LLC—complete with business bank accounts, fiat off-ramps, and the ability to own property and sign contracts. Governance is powered by locked KongSwap LP tokens, with 1% of dealflow distributed to ALEX stakers.",
-        url: "https://daopad.org",
+        description: "DAOPad turns LLCs into Smart Contracts, unifying company structure, legal agreements, and operations into a single autonomous system. Winner of DFINITY's WCHL2025 Hackathon (Real-World-Asset Track), it provides a white-glove framework for migrating to a legally recognized Wyoming DAO LLC—complete with business bank accounts, fiat off-ramps, and the ability to own property and sign contracts. Governance is powered by locked KongSwap LP tokens, with 1% of dealflow distributed to $ALEX stakers.",
+        links: &[
+            ProductLink { kind: LinkKind::App, url: "https://daopad.org" },
+        ],
         tags: &["DAO", "RWA", "DeFi"],
-        image: "/logos/projects/daopad_logo.png",
+        image: ImageVariants::png("/logos/projects/daopad_logo.png", 200).with_2x("/logos/projects/high-res/daopad_logo.png"),
     },
     Product {
         name: "KongLocker",
         tagline: "The only way to permanently lock liquidity on KongSwap, with proof of retained LP ownership.",
-        description: "KongLocker is a blackholed liquidity locking service for KongSwap. Projects can permanently lock their LP tokens to demonstrate long-term commitment, with the canister tracking individual shares of locked liquidity. These locked LP positions serve as governance weight in DAOPad, granting voting power proportional to permanently committed value.",
-        url: "https://konglocker.com",
+        description: "KongLocker is a blackholed liquidity locking service for [KongSwap](https://kongswap.io). Projects can permanently lock their LP tokens to demonstrate long-term commitment, with the canister tracking individual shares of locked liquidity. These locked LP positions serve as governance weight in DAOPad, granting voting power proportional to permanently committed value.",
+        links: &[
+            ProductLink { kind: LinkKind::App, url: "https://konglocker.com" },
+        ],
         tags: &["DeFi", "Infrastructure"],
-        image: "/logos/projects/kong_locker.png",
+        image: ImageVariants::png("/logos/projects/kong_locker.png", 200).with_2x("/logos/projects/high-res/kong_locker.png"),
     },
     Product {
         name: "Caffeine Launcher",
         tagline: "A fair launch mechanism for Caffeine.AI projects, enabling results-based investments over time.",
-        description: "Caffeine Launcher is a token launchpad on the Internet Computer featuring dual-token distribution mechanics. Projects can fair-launch with a 1% fee that automatically flows to buying and burning LBRY tokens, creating deflationary pressure tied directly to platform activity. Established tokens can integrate at no cost, with all launched tokens eligible for DAOPad governance integration.",
-        url: "https://caffeinelauncher.com",
+        description: "Caffeine Launcher is a token launchpad on the Internet Computer featuring dual-token distribution mechanics. Projects can fair-launch with a 1% fee that automatically flows to buying and burning $LBRY tokens, creating deflationary pressure tied directly to platform activity. Established tokens can integrate at no cost, with all launched tokens eligible for DAOPad governance integration.",
+        links: &[
+            ProductLink { kind: LinkKind::App, url: "https://caffeinelauncher.com" },
+        ],
         tags: &["DeFi", "Launchpad"],
-        image: "/logos/projects/lbry_fun.svg",
+        image: ImageVariants::svg("/logos/projects/lbry_fun.svg"),
     },
 ];
 
@@ -316,58 +488,571 @@ const METRICS: &[Metric] = &[
 ];
 
 const FOOTER_LINKS: &[FooterLink] = &[
-    FooterLink { url: "https://x.com/alexandria_lbry", icon: FooterIcon::Twitter, title: "Twitter" },
-    FooterLink { url: "https://github.com/AlexandriaDAO", icon: FooterIcon::Github, title: "Github" },
-    FooterLink { url: "https://lbry.app/info/whitepaper", icon: FooterIcon::Whitepaper, title: "Whitepaper" },
-    FooterLink { url: "https://lbry.app/info/audit", icon: FooterIcon::Audit, title: "Audit" },
-    FooterLink { url: "https://kongswap.io/stats/ysy5f-2qaaa-aaaap-qkmmq-cai", icon: FooterIcon::KongSwap, title: "KongSwap" },
-    FooterLink { url: "https://app.icpswap.com/swap?input=ryjl3-tyaaa-aaaaa-aaaba-cai&output=ysy5f-2qaaa-aaaap-qkmmq-cai", icon: FooterIcon::IcpSwap, title: "ICPSwap" },
-    FooterLink { url: "https://dexscreener.com/icp/kb4fz-oiaaa-aaaag-qnema-cai", icon: FooterIcon::DexScreener, title: "DexScreener" },
-    FooterLink { url: "https://icptokens.net/token/ysy5f-2qaaa-aaaap-qkmmq-cai", icon: FooterIcon::IcpTokens, title: "ICPTokens" },
+    FooterLink { group: FooterGroup::Community, url: "https://x.com/alexandria_lbry", icon: Icon::X, image: None, title: "Twitter" },
+    FooterLink { group: FooterGroup::Community, url: "https://github.com/AlexandriaDAO", icon: Icon::Github, image: None, title: "Github" },
+    FooterLink { group: FooterGroup::Docs, url: "https://lbry.app/info/whitepaper", icon: Icon::Document, image: None, title: "Whitepaper" },
+    FooterLink { group: FooterGroup::Docs, url: "https://lbry.app/info/audit", icon: Icon::ShieldCheck, image: None, title: "Audit" },
+    FooterLink { group: FooterGroup::Markets, url: "https://kongswap.io/stats/ysy5f-2qaaa-aaaap-qkmmq-cai", icon: Icon::Crown, image: Some("/logos/external/kongswap.png"), title: "KongSwap" },
+    FooterLink { group: FooterGroup::Markets, url: "https://app.icpswap.com/swap?input=ryjl3-tyaaa-aaaaa-aaaba-cai&output=ysy5f-2qaaa-aaaap-qkmmq-cai", icon: Icon::Swap, image: Some("/logos/external/icpswap.png"), title: "ICPSwap" },
+    FooterLink { group: FooterGroup::Markets, url: "https://dexscreener.com/icp/kb4fz-oiaaa-aaaag-qnema-cai", icon: Icon::Candlestick, image: Some("/logos/external/dexscreener.png"), title: "DexScreener" },
+    FooterLink { group: FooterGroup::Markets, url: "https://icptokens.net/token/ysy5f-2qaaa-aaaap-qkmmq-cai", icon: Icon::Coin, image: Some("/logos/external/icptokens.png"), title: "ICPTokens" },
 ];
 
 pub struct AlexandriaApp {
     colors: ColorPalette,
-    base_url: String,
+    assets: AssetResolver,
     typewriter: TypewriterState,
     start_time: f64,
     last_frame_time: f64,
     hover: HoverState,
+    /// Glossary tooltip kept open by a tap, for touch screens without hover
+    pinned_glossary: Option<egui::Id>,
+    /// Product grid column count and the tallest card in each row last frame
+    card_row_heights: (usize, Vec<f32>),
+    /// User multiplier applied on top of the fluid type scale
+    font_scale: f32,
+    fonts: BrandFonts,
+    logos: LogoLoader,
+    /// Wake-ups requested by animations during the current frame
+    repaint: RepaintSchedule,
+    /// Updated by browser callbacks; animation time stands still while hidden
+    visibility: std::rc::Rc<std::cell::RefCell<Visibility>>,
+    quality: QualityGovernor,
+    galleys: GalleyCache,
+    debug: DebugHud,
+    /// Where each hoverable element was laid out in the last frame
+    hit_areas: Vec<(Target, Rect)>,
+    /// Seconds since start from a simulated clock, instead of the system's
+    simulated_time: Option<f64>,
 }
 
 impl Default for AlexandriaApp {
     fn default() -> Self {
-        // Get the base URL from the current window location
-        let base_url = web_sys::window()
-            .and_then(|w| w.location().origin().ok())
-            .unwrap_or_default();
-        let start_time = web_sys::window()
-            .and_then(|w| w.performance())
-            .map(|p| p.now() / 1000.0)
-            .unwrap_or(0.0);
+        // Resolve assets against the current page, unless configured elsewhere
+        #[cfg(target_arch = "wasm32")]
+        let assets = AssetResolver::new(&page_url(), meta_content("asset-base").as_deref().or(ASSET_BASE));
+        #[cfg(not(target_arch = "wasm32"))]
+        let assets = AssetResolver::new("", ASSET_BASE);
+        let start_time = now();
         Self {
             colors: ColorPalette::default(),
-            base_url,
+            assets,
             typewriter: TypewriterState::default(),
             start_time,
             last_frame_time: start_time,
             hover: HoverState::default(),
+            pinned_glossary: None,
+            card_row_heights: (0, Vec::new()),
+            font_scale: 1.0,
+            fonts: BrandFonts::default(),
+            logos: LogoLoader::default(),
+            repaint: RepaintSchedule::new(0.0),
+            visibility: Default::default(),
+            quality: QualityGovernor::default(),
+            galleys: GalleyCache::default(),
+            debug: DebugHud::default(),
+            hit_areas: Vec::new(),
+            simulated_time: None,
         }
     }
 }
 
 impl AlexandriaApp {
+    #[cfg(target_arch = "wasm32")]
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
         // Install image loaders for loading PNGs from URLs
         egui_extras::install_image_loaders(&cc.egui_ctx);
-        let app = Self::default();
-        web_sys::console::log_1(&format!("[RUST] Base URL for images: {}", app.base_url).into());
-        web_sys::console::log_1(&format!("[RUST] Example image URL: {}{}", app.base_url, "/logos/projects/alex.png").into());
+        let mut app = Self::default();
+        app.fonts.install(&cc.egui_ctx);
+        install_style(&cc.egui_ctx);
+        visibility::observe(&cc.egui_ctx, &app.visibility);
+        // `?font-scale=1.25` lets visitors enlarge text independently of browser zoom
+        if let Some(scale) = query_param("font-scale").and_then(|v| v.parse().ok()) {
+            app.set_font_scale(scale);
+        }
+        // `?debug=1` opens the frame profiler HUD, `?debug=outlines` adds hit area outlines
+        if let Some(debug) = query_param("debug") {
+            app.debug = DebugHud::from_query(&debug);
+        }
+        log::debug!("example image URL: {}", app.assets.resolve("/logos/projects/alex.png"));
         app
     }
 
-    fn centered_label(&self, ui: &mut egui::Ui, text: &str, font_size: f32, color: Color32) {
-        ui.label(egui::RichText::new(text).size(font_size).color(color));
+    /// Run animations on a simulated clock that reads `time` seconds since
+    /// start, instead of the system clock. Set before every frame to step
+    /// through an animation deterministically, as offscreen rendering does.
+    pub fn set_time(&mut self, time: f64) {
+        if self.simulated_time.is_none() {
+            self.start_time = 0.0;
+            self.last_frame_time = 0.0;
+        }
+        self.simulated_time = Some(time);
+    }
+
+    /// Where `target` was laid out in the last frame, in screen points
+    pub fn hit_area(&self, target: Target) -> Option<Rect> {
+        self.hit_areas.iter().find(|(t, _)| *t == target).map(|(_, rect)| *rect)
+    }
+
+    /// Set the multiplier applied to every font size, clamped to a readable range
+    pub fn set_font_scale(&mut self, scale: f32) {
+        self.font_scale = scale.clamp(*tokens::FONT_SCALE_RANGE.start(), *tokens::FONT_SCALE_RANGE.end());
+    }
+
+    /// Rust-themed frame shared by all tooltips on the page
+    fn tooltip_frame(&self) -> egui::Frame {
+        egui::Frame::none()
+            .fill(Color32::from_rgb(20, 20, 20))
+            .stroke(Stroke::new(1.5, self.colors.accent_rust))
+            .rounding(Rounding::same(4.0))
+            .inner_margin(egui::Margin::symmetric(12.0, 8.0))
+    }
+
+    /// Lay out wrapped copy with inline markup and glossary terms underlined in copper.
+    /// Terms and links are queued as hotspots for the caller to register.
+    fn rich_label(&mut self, ui: &mut egui::Ui, text: &str, font_size: f32, color: Color32, hotspots: &mut Vec<Hotspot>) {
+        let colors = self.colors;
+        let font = FontId::new(font_size, FontFamily::Proportional);
+        let width = ui.available_width();
+        let (galley, targets) = self.galleys.rich(ui, text, font.clone(), color, width, || {
+            let base = TextFormat::simple(font, color);
+            let mut job = LayoutJob::default();
+            job.wrap.max_width = width;
+            let mut targets = Vec::new(); // (section index, hotspot kind)
+            for span in markup::parse(text) {
+                let mut format = base.clone();
+                format.italics = span.italic;
+                // No bold face is loaded, and copy is already drawn in the
+                // primary text color, so bold stands out in the ember accent
+                if span.bold {
+                    format.color = colors.accent_ember;
+                }
+                match span.kind {
+                    SpanKind::Text => {
+                        for segment in glossary::segments(span.text) {
+                            match segment {
+                                Segment::Plain(s) => job.append(s, 0.0, format.clone()),
+                                Segment::Term(s, idx) => {
+                                    targets.push((job.sections.len() as u32, HotspotKind::Glossary(idx)));
+                                    let underline = Stroke::new(1.0, colors.accent_copper);
+                                    job.append(s, 0.0, TextFormat { underline, ..format.clone() });
+                                }
+                            }
+                        }
+                    }
+                    SpanKind::Token => {
+                        job.append(span.text, 0.0, TextFormat { color: colors.accent_ember, background: colors.tag_bg, ..format });
+                    }
+                    SpanKind::Link(url) => {
+                        targets.push((job.sections.len() as u32, HotspotKind::Link(url.to_string())));
+                        let underline = Stroke::new(1.0, colors.accent_rust);
+                        job.append(span.text, 0.0, TextFormat { color: colors.accent_rust, underline, ..format });
+                    }
+                }
+            }
+            (job, targets)
+        });
+        let (rect, _) = ui.allocate_exact_size(galley.size(), Sense::hover());
+
+        // A target can wrap across rows, so it gets one hotspot per row. Ids
+        // are salted with the text, as one `ui` can hold several labels.
+        for (n, (section, kind)) in targets.iter().enumerate() {
+            for (row_idx, row) in galley.rows.iter().enumerate() {
+                let r = row.glyphs.iter()
+                    .filter(|g| g.section_index == *section)
+                    .fold(Rect::NOTHING, |acc, g| acc.union(g.logical_rect()));
+                if r.is_positive() {
+                    hotspots.push(Hotspot {
+                        rect: r.translate(rect.min.to_vec2()),
+                        id: ui.id().with(("inline", text, n, row_idx)),
+                        kind: kind.clone(),
+                    });
+                }
+            }
+        }
+
+        ui.painter().galley(rect.min, galley, color);
+    }
+
+    /// Draw an action bar icon with its tooltip, opening the link on click
+    fn action_hotspot(&self, ui: &egui::Ui, resp: &egui::Response, link: &ProductLink) {
+        let colors = self.colors;
+        let hov = resp.hovered();
+        if hov {
+            let glow = Color32::from_rgba_unmultiplied(colors.accent_ember.r(), colors.accent_ember.g(), colors.accent_ember.b(), 60);
+            ui.painter().circle_stroke(resp.rect.center(), resp.rect.width() / 2.0 + 2.0, Stroke::new(1.5, glow));
+            ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+        }
+        let col = if hov { colors.accent_ember } else { colors.text_muted };
+        icons::paint(ui.painter(), link.kind.icon(), resp.rect.center(), resp.rect.width() * 0.75, col);
+
+        resp.clone().on_hover_ui_at_pointer(|ui| {
+            self.tooltip_frame().show(ui, |ui| {
+                ui.label(egui::RichText::new(link.kind.label()).size(13.0).color(colors.accent_ember));
+                ui.label(egui::RichText::new(link.url).size(11.0).color(colors.text_muted));
+            });
+        });
+        if resp.clicked() {
+            open_url(link.url);
+        }
+    }
+
+    /// Brighten an inline link under the pointer and open it on click
+    fn link_hotspot(&self, ui: &egui::Ui, resp: &egui::Response, url: &str) {
+        if resp.hovered() {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+            let r = resp.rect;
+            ui.painter().line_segment([r.left_bottom(), r.right_bottom()], Stroke::new(1.5, self.colors.accent_ember));
+        }
+        if resp.clicked() {
+            open_url(url);
+        }
+    }
+
+    /// Show a glossary definition while its term is hovered or pinned by a tap.
+    /// Returns true if the term was tapped this frame.
+    fn glossary_tooltip(&mut self, resp: &egui::Response, idx: usize) -> bool {
+        let colors = self.colors;
+        if resp.clicked() {
+            self.pinned_glossary = if self.pinned_glossary == Some(resp.id) { None } else { Some(resp.id) };
+        }
+        if resp.hovered() {
+            resp.ctx.set_cursor_icon(egui::CursorIcon::Help);
+        }
+        if resp.hovered() || self.pinned_glossary == Some(resp.id) {
+            let entry = &GLOSSARY[idx];
+            resp.show_tooltip_ui(|ui| {
+                self.tooltip_frame().show(ui, |ui| {
+                    ui.set_max_width(260.0);
+                    ui.label(egui::RichText::new(entry.term).size(13.0).color(colors.accent_ember));
+                    ui.add_space(4.0);
+                    ui.label(egui::RichText::new(entry.definition).size(12.0).color(colors.text_secondary));
+                });
+            });
+        }
+        resp.clicked()
+    }
+
+    /// Lay out the metrics in a grid with as many cells per row as fit. Rows are
+    /// balanced, so four metrics that don't fit on one line become 2×2, not 3+1.
+    fn metrics_grid(&mut self, ui: &mut egui::Ui, typo: &Typography, breakpoint: Breakpoint, dt: f32) {
+        let colors = self.colors;
+        let n = METRICS.len();
+        if n == 0 { return; }
+
+        let galleys: Vec<_> = METRICS.iter().map(|m| (
+            self.galleys.no_wrap(ui, m.value, typo.metric_value_font(), colors.text_primary),
+            self.galleys.label(ui, m.label, typo.metric_label_font(), colors.text_muted),
+        )).collect();
+
+        // A cell is never narrower than its widest value, so nothing overflows
+        let widest = galleys.iter().map(|(v, l)| v.size().x.max(l.size().x)).fold(0.0, f32::max);
+        let cell_min = breakpoint.metric_width().max(widest + 16.0);
+        let available = ui.available_width();
+        let fit = ((available / cell_min).floor() as usize).clamp(1, n);
+        let rows = n.div_ceil(fit);
+        let cols = n.div_ceil(rows);
+        let cell_w = available / cols as f32;
+        let content_h = galleys.iter().map(|(v, l)| v.size().y + 4.0 + l.size().y).fold(0.0, f32::max);
+        let cell_h = (content_h + 16.0).max(60.0);
+
+        let (grid, _) = ui.allocate_exact_size(Vec2::new(available, rows as f32 * cell_h), Sense::hover());
+        let divider = Stroke::new(1.0, colors.border);
+        for row in 1..rows {
+            let y = grid.top() + row as f32 * cell_h;
+            ui.painter().line_segment([Pos2::new(grid.left(), y), Pos2::new(grid.right(), y)], divider);
+        }
+
+        for (idx, (value, label)) in galleys.into_iter().enumerate() {
+            let (row, col) = (idx / cols, idx % cols);
+            // Center a short last row
+            let in_row = if row == rows - 1 { n - row * cols } else { cols };
+            let x = grid.left() + (cols - in_row) as f32 * cell_w / 2.0 + col as f32 * cell_w;
+            let cell = Rect::from_min_size(Pos2::new(x, grid.top() + row as f32 * cell_h), Vec2::new(cell_w, cell_h));
+            if col > 0 {
+                ui.painter().line_segment([cell.left_top() + Vec2::Y * 12.0, cell.left_bottom() - Vec2::Y * 12.0], divider);
+            }
+
+            // Update hover state
+            let hover_rect = Rect::from_center_size(cell.center(), Vec2::new(cell_min.min(cell_w) - 8.0, cell_h - 8.0));
+            let hover_sense = ui.interact(hover_rect, ui.id().with(("metric", idx)), Sense::hover());
+            self.debug.outline(hover_rect, Outline::Metric);
+            self.hit_areas.push((Target::Metric(idx), hover_rect));
+            let hover_intensity = self.hover.update_metric(idx, hover_sense.hovered(), dt);
+
+            // Draw glow effect behind metric
+            if hover_intensity > 0.01 {
+                let glow_color = lerp_color(colors.accent_rust, colors.accent_ember, hover_intensity);
+                let alpha = (40.0 * hover_intensity) as u8;
+                let bg = Color32::from_rgba_unmultiplied(glow_color.r(), glow_color.g(), glow_color.b(), alpha);
+                ui.painter().rect_filled(hover_rect, Rounding::same(8.0), bg);
+            }
+
+            // Metric value with glow color on hover
+            let value_color = if hover_intensity > 0.5 {
+                lerp_color(colors.text_primary, colors.accent_ember, (hover_intensity - 0.5) * 2.0)
+            } else {
+                colors.text_primary
+            };
+            let top = cell.center().y - (value.size().y + 4.0 + label.size().y) / 2.0;
+            let label_pos = Pos2::new(cell.center().x - label.size().x / 2.0, top + value.size().y + 4.0);
+            ui.painter().galley(Pos2::new(cell.center().x - value.size().x / 2.0, top), value, value_color);
+            ui.painter().galley(label_pos, label, colors.text_muted);
+        }
+    }
+
+    /// Lay out the footer links in labeled sections. Sections sit side by side
+    /// above the mobile breakpoint and stack below it; links wrap onto new rows
+    /// within a section rather than overflowing the viewport.
+    fn footer(&mut self, ui: &mut egui::Ui, typo: &Typography, breakpoint: Breakpoint, time: f64, dt: f32) {
+        let colors = self.colors;
+        let (isz, icon_gap) = breakpoint.footer_icon();
+        let cell = isz + 8.0;
+        let show_labels = breakpoint.footer_labels();
+        let label_font = FontId::new(typo.description_size, FontFamily::Proportional);
+
+        let sections: Vec<_> = FooterGroup::ALL
+            .iter()
+            .map(|&group| (group, FOOTER_LINKS.iter().enumerate().filter(|(_, l)| l.group == group).collect::<Vec<_>>()))
+            .filter(|(_, links)| !links.is_empty())
+            .collect();
+        if sections.is_empty() { return; }
+
+        let available = ui.available_width();
+        let cols = if breakpoint == Breakpoint::Mobile { 1 } else { sections.len() };
+        let col_w = available / cols as f32;
+        let row_gap = 8.0;
+
+        // Lay out every section first so the footer can be allocated in one go
+        struct Item<'a> { idx: usize, link: &'a FooterLink, label: Option<std::sync::Arc<egui::Galley>>, offset: Vec2, width: f32 }
+        let mut laid_out = Vec::new();
+        for (group, links) in &sections {
+            let heading = self.galleys.label(ui, group.label(), typo.metric_label_font(), colors.text_muted);
+            let top = heading.size().y + 10.0;
+            let mut rows: Vec<Vec<Item>> = vec![Vec::new()];
+            let mut x = 0.0;
+            for &(idx, link) in links {
+                let label = show_labels.then(|| self.galleys.no_wrap(ui, link.title, label_font.clone(), colors.text_muted));
+                let width = cell + label.as_ref().map_or(0.0, |g| g.size().x + 4.0);
+                if x > 0.0 && x + width > col_w {
+                    rows.push(Vec::new());
+                    x = 0.0;
+                }
+                let y = top + (rows.len() - 1) as f32 * (cell + row_gap);
+                rows.last_mut().unwrap().push(Item { idx, link, label, offset: Vec2::new(x, y), width });
+                x += width + icon_gap;
+            }
+            // Center each row within the section
+            for row in &mut rows {
+                let row_w = row.iter().map(|i| i.width).sum::<f32>() + icon_gap * (row.len() - 1) as f32;
+                for item in row.iter_mut() {
+                    item.offset.x += ((col_w - row_w) / 2.0).max(0.0);
+                }
+            }
+            let height = top + rows.len() as f32 * (cell + row_gap) - row_gap;
+            laid_out.push((heading, rows.into_iter().flatten().collect::<Vec<_>>(), height));
+        }
+
+        let section_gap = 24.0;
+        let row_heights: Vec<f32> = laid_out
+            .chunks(cols)
+            .map(|row| row.iter().map(|(_, _, h)| *h).fold(0.0, f32::max))
+            .collect();
+        let total_h = row_heights.iter().sum::<f32>() + section_gap * (row_heights.len() - 1) as f32;
+        let (footer, _) = ui.allocate_exact_size(Vec2::new(available, total_h), Sense::hover());
+
+        let mut y = footer.top();
+        for (row_idx, row) in laid_out.chunks(cols).enumerate() {
+            for (col, (heading, items, _)) in row.iter().enumerate() {
+                let origin = Pos2::new(footer.left() + col as f32 * col_w, y);
+                let heading_pos = Pos2::new(origin.x + (col_w - heading.size().x) / 2.0, origin.y);
+                ui.painter().galley(heading_pos, heading.clone(), colors.text_muted);
+
+                for item in items {
+                    let r = Rect::from_min_size(origin + item.offset, Vec2::new(item.width, cell));
+                    let icon_rect = Rect::from_min_size(r.min, Vec2::splat(cell));
+                    let rsp = ui.interact(r, ui.id().with(("footer", item.idx)), Sense::click());
+                    self.debug.outline(r, Outline::Footer);
+                    self.hit_areas.push((Target::Footer(item.idx), r));
+                    let hov = rsp.hovered();
+                    let hover_t = self.hover.update_footer(item.idx, hov, dt);
+
+                    // Cyberpunk glow on hover
+                    if hov {
+                        self.repaint.asap();
+                        // Animated glow color
+                        let phase = (time * 4.0) as f32;
+                        let glow_t = phase.sin() * 0.5 + 0.5;
+                        let glow_color = lerp_color(colors.accent_copper, colors.accent_ember, glow_t);
+
+                        // Multiple glow layers, or just the innermost at reduced quality
+                        let skip = if self.quality.level().layered_glows() { 0 } else { 2 };
+                        for (alpha, expand) in [(30, 12.0), (50, 8.0), (80, 4.0)].into_iter().skip(skip) {
+                            let glow = Color32::from_rgba_unmultiplied(
+                                glow_color.r(), glow_color.g(), glow_color.b(), alpha
+                            );
+                            ui.painter().circle_stroke(icon_rect.center(), (isz / 2.0) + expand, Stroke::new(2.0, glow));
+                        }
+                    }
+
+                    // Logos sit dimmed until hovered; the vector icon stands in while they load
+                    let sc = 1.0 + 0.15 * hover_t;
+                    let tint = lerp_color(colors.text_muted, colors.accent_ember, hover_t);
+                    let has_image = item.link.image.is_some_and(|path| self.paint_icon_image(ui.painter(), path, icon_rect.center(), isz * sc, tint, time));
+                    if !has_image {
+                        icons::paint(ui.painter(), item.link.icon, icon_rect.center(), isz * sc * 0.75, tint);
+                    }
+                    if let Some(label) = &item.label {
+                        let pos = Pos2::new(icon_rect.right() + 4.0, r.center().y - label.size().y / 2.0);
+                        ui.painter().galley(pos, label.clone(), tint);
+                    }
+
+                    // Rusty tooltip on hover, unless the title is already shown
+                    if item.label.is_none() {
+                        rsp.clone().on_hover_ui_at_pointer(|ui| {
+                            self.tooltip_frame().show(ui, |ui| {
+                                ui.label(
+                                    egui::RichText::new(item.link.title)
+                                        .size(13.0)
+                                        .color(colors.accent_ember)
+                                );
+                            });
+                        });
+                    }
+
+                    if rsp.clicked() { open_url(item.link.url); }
+                    if hov { ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand); }
+                }
+            }
+            y += row_heights[row_idx] + section_gap;
+        }
+    }
+
+    /// Lay out one product card with its hover effects. `min_height` stretches
+    /// the card to match the rest of its grid row. Returns the height the content
+    /// needed on its own, and whether a glossary term was tapped.
+    fn product_card(&mut self, ui: &mut egui::Ui, product_idx: usize, typo: &Typography, time: f64, dt: f32, min_height: f32) -> (f32, bool) {
+        let p = &PRODUCTS[product_idx];
+        let colors = self.colors;
+        let mut hotspots = Vec::new();
+        let mut tapped_term = false;
+        let mut content_height = 0.0;
+        let row = ui.vertical(|ui| {
+            ui.set_min_height(min_height);
+            let top = ui.cursor().min.y;
+            ui.add_space(16.0);
+
+            // Product header with image
+            ui.horizontal(|ui| {
+                // Product logo - the slot keeps its size while loading or after a failure
+                let img_size = 40.0;
+                let (logo_rect, _) = ui.allocate_exact_size(Vec2::splat(img_size), Sense::hover());
+                self.paint_logo(ui, logo_rect, p, time);
+                ui.add_space(12.0);
+
+                // Leave room on the right for the action bar
+                let action_size = 22.0;
+                let action_gap = 6.0;
+                let bar_width = p.links.len() as f32 * (action_size + action_gap);
+
+                ui.vertical(|ui| {
+                    ui.set_max_width((ui.available_width() - bar_width).max(0.0));
+                    ui.label(egui::RichText::new(p.name).font(typo.product_name_font()).color(colors.text_primary));
+                    ui.add_space(4.0);
+                    ui.horizontal_wrapped(|ui| {
+                        for t in p.tags {
+                            let g = self.galleys.label(ui, t, typo.tag_font(), colors.tag_text);
+                            let sz = g.size() + Vec2::new(10.0, 4.0);
+                            let (tr, tag_resp) = ui.allocate_exact_size(sz, Sense::hover());
+
+                            // Tag glow on hover
+                            let tag_glow = if tag_resp.hovered() { 1.0 } else { 0.0 };
+                            if tag_glow > 0.0 {
+                                if self.quality.level().tag_pulse() {
+                                    self.repaint.asap();
+                                }
+                                self.draw_tag_glow(ui.painter(), tr, tag_glow, time);
+                            }
+
+                            ui.painter().rect_filled(tr, Rounding::same(3.0), colors.tag_bg);
+                            ui.painter().galley(tr.min + Vec2::new(5.0, 2.0), g, colors.tag_text);
+                        }
+                    });
+                });
+
+                // Action bar: icons are drawn once their hotspots are registered
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                    ui.spacing_mut().item_spacing.x = action_gap;
+                    for (link_idx, link) in p.links.iter().enumerate().rev() {
+                        let (r, _) = ui.allocate_exact_size(Vec2::splat(action_size), Sense::hover());
+                        hotspots.push(Hotspot {
+                            rect: r,
+                            id: ui.id().with(("action", link_idx)),
+                            kind: HotspotKind::Action(link.clone()),
+                        });
+                    }
+                });
+            });
+
+            ui.add_space(12.0);
+            self.rich_label(ui, p.tagline, typo.tagline_size, colors.text_primary, &mut hotspots);
+            ui.add_space(8.0);
+            self.rich_label(ui, p.description, typo.description_size, colors.text_secondary, &mut hotspots);
+            ui.add_space(16.0);
+            content_height = ui.cursor().min.y - top;
+        });
+
+        let card_rect = row.response.rect;
+        ui.painter().line_segment([card_rect.left_bottom(), card_rect.right_bottom()], Stroke::new(1.0, colors.border));
+        let click = ui.interact(card_rect, ui.id().with(p.name), Sense::click());
+        self.debug.outline(card_rect, Outline::Card);
+        self.hit_areas.push((Target::Product(product_idx), card_rect));
+        // Hotspots on top of the card take its hover, but the card should keep glowing
+        let is_hovered = click.contains_pointer();
+
+        for h in &hotspots {
+            let resp = ui.interact(h.rect, h.id, Sense::click());
+            match &h.kind {
+                HotspotKind::Glossary(idx) => tapped_term |= self.glossary_tooltip(&resp, *idx),
+                HotspotKind::Link(url) => self.link_hotspot(ui, &resp, url),
+                HotspotKind::Action(link) => self.action_hotspot(ui, &resp, link),
+            }
+        }
+
+        // Update hover animation state
+        let hover_intensity = self.hover.update_hover(product_idx, is_hovered, dt);
+        let scanline_pos = self.hover.update_scanline(product_idx, is_hovered, dt);
+
+        // Draw cyberpunk effects (in reverse order for proper layering)
+        // 1. Background glow
+        self.draw_hover_bg(ui.painter(), card_rect, hover_intensity);
+
+        // 2. Glowing border, which pulses for as long as it's visible
+        if hover_intensity > 0.01 {
+            self.repaint.asap();
+        }
+        self.draw_glow_border(ui.painter(), card_rect, hover_intensity, time);
+
+        // 3. Animated scanline sweep
+        self.draw_scanline(ui.painter(), card_rect, scanline_pos, hover_intensity);
+
+        if click.clicked() { open_url(p.primary_url()); }
+        if click.hovered() { ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand); }
+
+        (content_height, tapped_term)
+    }
+
+    /// Paint a product's logo in `rect`: the image once it's loaded, a skeleton
+    /// while it loads and a monogram if it fails
+    fn paint_logo(&mut self, ui: &egui::Ui, rect: Rect, p: &Product, time: f64) {
+        match self.logos.poll_variants(ui.ctx(), &self.assets, &p.image, rect.width(), time) {
+            LogoState::Ready(texture) => egui::Image::from_texture(texture).rounding(rect.width() / 10.0).paint_at(ui, rect),
+            LogoState::Loading => {
+                self.repaint.asap();
+                self.draw_skeleton(ui.painter(), rect, time);
+            }
+            LogoState::Failed => self.draw_monogram(ui.painter(), rect, p.name),
+        }
     }
 
     /// Draw a cyberpunk glowing border around a rect
@@ -378,8 +1063,9 @@ impl AlexandriaApp {
         // Animate gradient position for "energy flow" effect
         let phase = (time * 2.0) as f32;
 
-        // Multiple glow layers for depth
-        for (i, (alpha_mult, expand)) in [(0.15, 8.0), (0.3, 4.0), (0.6, 2.0)].iter().enumerate() {
+        // Multiple glow layers for depth, or just the innermost at reduced quality
+        let skip = if self.quality.level().layered_glows() { 0 } else { 2 };
+        for (i, (alpha_mult, expand)) in [(0.15, 8.0), (0.3, 4.0), (0.6, 2.0)].iter().enumerate().skip(skip) {
             let glow_rect = rect.expand(*expand * intensity);
 
             // Gradient cycles between rust → copper → ember (forged metal effect)
@@ -398,7 +1084,7 @@ impl AlexandriaApp {
 
     /// Draw an animated scanline sweep effect
     fn draw_scanline(&self, painter: &egui::Painter, rect: Rect, scanline_pos: f32, intensity: f32) {
-        if intensity < 0.01 || scanline_pos < 0.0 || scanline_pos > 1.0 { return; }
+        if intensity < 0.01 || !(0.0..=1.0).contains(&scanline_pos) { return; }
 
         let colors = self.colors;
         let y = rect.top() + rect.height() * scanline_pos;
@@ -422,6 +1108,8 @@ impl AlexandriaApp {
             Stroke::new(2.0 * intensity, scan_color),
         );
 
+        if !self.quality.level().scanline_glow() { return; }
+
         // Glow above and below
         let glow_alpha = (60.0 * intensity) as u8;
         let glow_color = Color32::from_rgba_unmultiplied(
@@ -451,13 +1139,43 @@ impl AlexandriaApp {
         painter.rect_filled(rect, Rounding::same(4.0), bg_color);
     }
 
+    /// Placeholder with a highlight sweeping across while an image loads
+    fn draw_skeleton(&self, painter: &egui::Painter, rect: Rect, time: f64) {
+        painter.rect_filled(rect, Rounding::same(4.0), self.colors.tag_bg);
+
+        let period = 1.2;
+        let t = (time % period / period) as f32;
+        let band = rect.width() * 0.6;
+        let x = rect.left() - band + (rect.width() + band * 2.0) * t;
+        let highlight = Color32::from_rgba_unmultiplied(255, 255, 255, 18);
+        let mut mesh = egui::Mesh::default();
+        for (i, (bx, color)) in [(x - band, Color32::TRANSPARENT), (x, highlight), (x + band, Color32::TRANSPARENT)].into_iter().enumerate() {
+            mesh.colored_vertex(Pos2::new(bx, rect.top()), color);
+            mesh.colored_vertex(Pos2::new(bx, rect.bottom()), color);
+            if i > 0 {
+                let base = (i as u32 - 1) * 2;
+                mesh.add_triangle(base, base + 1, base + 2);
+                mesh.add_triangle(base + 1, base + 3, base + 2);
+            }
+        }
+        painter.with_clip_rect(rect.intersect(painter.clip_rect())).add(mesh);
+    }
+
+    /// Initials tile standing in for a logo that failed to load
+    fn draw_monogram(&self, painter: &egui::Painter, rect: Rect, name: &str) {
+        painter.rect_filled(rect, Rounding::same(4.0), self.colors.accent_copper);
+        let font = FontId::new(rect.height() * 0.4, FontFamily::Name(tokens::DISPLAY_FAMILY.into()));
+        painter.text(rect.center(), egui::Align2::CENTER_CENTER, logos::initials(name), font, self.colors.bg_primary);
+    }
+
     /// Draw glowing tag on hover
     fn draw_tag_glow(&self, painter: &egui::Painter, rect: Rect, intensity: f32, time: f64) {
         if intensity < 0.01 { return; }
 
         let colors = self.colors;
         let phase = (time * 3.0) as f32;
-        let pulse = (phase.sin() * 0.3 + 0.7) * intensity;
+        let wave = if self.quality.level().tag_pulse() { phase.sin() } else { 0.0 };
+        let pulse = (wave * 0.3 + 0.7) * intensity;
 
         // Outer glow
         let glow_rect = rect.expand(2.0 * pulse);
@@ -468,124 +1186,177 @@ impl AlexandriaApp {
         painter.rect_stroke(glow_rect, Rounding::same(5.0), Stroke::new(1.0, glow_color));
     }
 
-    fn render_footer_icon(&self, painter: &egui::Painter, icon: FooterIcon, center: Pos2, size: f32, color: Color32) {
-        match icon {
-            FooterIcon::Twitter => {
-                // X logo
-                let s = size * 0.35;
-                painter.line_segment([Pos2::new(center.x - s, center.y - s), Pos2::new(center.x + s, center.y + s)], Stroke::new(2.5, color));
-                painter.line_segment([Pos2::new(center.x + s, center.y - s), Pos2::new(center.x - s, center.y + s)], Stroke::new(2.5, color));
-            }
-            FooterIcon::Github => {
-                // Circle with dot (simplified octocat)
-                painter.circle_stroke(center, size * 0.35, Stroke::new(2.0, color));
-                painter.circle_filled(center, size * 0.12, color);
-            }
-            FooterIcon::Whitepaper => {
-                // Document icon
-                let s = size * 0.3;
-                painter.rect_stroke(Rect::from_center_size(center, Vec2::new(s * 1.3, s * 1.7)), 2.0, Stroke::new(1.5, color));
-                // Lines inside document
-                let line_y1 = center.y - s * 0.4;
-                let line_y2 = center.y;
-                let line_y3 = center.y + s * 0.4;
-                let lx = s * 0.4;
-                painter.line_segment([Pos2::new(center.x - lx, line_y1), Pos2::new(center.x + lx, line_y1)], Stroke::new(1.0, color));
-                painter.line_segment([Pos2::new(center.x - lx, line_y2), Pos2::new(center.x + lx, line_y2)], Stroke::new(1.0, color));
-                painter.line_segment([Pos2::new(center.x - lx, line_y3), Pos2::new(center.x + lx * 0.5, line_y3)], Stroke::new(1.0, color));
-            }
-            FooterIcon::Audit => {
-                // Shield icon
-                let s = size * 0.3;
-                let pts = vec![
-                    Pos2::new(center.x, center.y - s),
-                    Pos2::new(center.x + s * 0.7, center.y - s * 0.35),
-                    Pos2::new(center.x + s * 0.7, center.y + s * 0.15),
-                    Pos2::new(center.x, center.y + s),
-                    Pos2::new(center.x - s * 0.7, center.y + s * 0.15),
-                    Pos2::new(center.x - s * 0.7, center.y - s * 0.35),
-                ];
-                painter.add(egui::Shape::convex_polygon(pts, Color32::TRANSPARENT, Stroke::new(1.5, color)));
-                // Checkmark inside
-                painter.line_segment([Pos2::new(center.x - s * 0.25, center.y), Pos2::new(center.x - s * 0.05, center.y + s * 0.2)], Stroke::new(1.5, color));
-                painter.line_segment([Pos2::new(center.x - s * 0.05, center.y + s * 0.2), Pos2::new(center.x + s * 0.25, center.y - s * 0.15)], Stroke::new(1.5, color));
-            }
-            FooterIcon::KongSwap => {
-                // Crown/K shape for Kong
-                let s = size * 0.35;
-                // K shape
-                painter.line_segment([Pos2::new(center.x - s * 0.5, center.y - s), Pos2::new(center.x - s * 0.5, center.y + s)], Stroke::new(2.0, color));
-                painter.line_segment([Pos2::new(center.x - s * 0.5, center.y), Pos2::new(center.x + s * 0.5, center.y - s)], Stroke::new(2.0, color));
-                painter.line_segment([Pos2::new(center.x - s * 0.5, center.y), Pos2::new(center.x + s * 0.5, center.y + s)], Stroke::new(2.0, color));
-            }
-            FooterIcon::IcpSwap => {
-                // Swap arrows icon
-                let s = size * 0.3;
-                // Right arrow
-                painter.line_segment([Pos2::new(center.x - s, center.y - s * 0.4), Pos2::new(center.x + s, center.y - s * 0.4)], Stroke::new(2.0, color));
-                painter.line_segment([Pos2::new(center.x + s * 0.5, center.y - s * 0.8), Pos2::new(center.x + s, center.y - s * 0.4)], Stroke::new(2.0, color));
-                painter.line_segment([Pos2::new(center.x + s * 0.5, center.y), Pos2::new(center.x + s, center.y - s * 0.4)], Stroke::new(2.0, color));
-                // Left arrow
-                painter.line_segment([Pos2::new(center.x + s, center.y + s * 0.4), Pos2::new(center.x - s, center.y + s * 0.4)], Stroke::new(2.0, color));
-                painter.line_segment([Pos2::new(center.x - s * 0.5, center.y), Pos2::new(center.x - s, center.y + s * 0.4)], Stroke::new(2.0, color));
-                painter.line_segment([Pos2::new(center.x - s * 0.5, center.y + s * 0.8), Pos2::new(center.x - s, center.y + s * 0.4)], Stroke::new(2.0, color));
-            }
-            FooterIcon::DexScreener => {
-                // Chart/graph icon
-                let s = size * 0.35;
-                // Axes
-                painter.line_segment([Pos2::new(center.x - s, center.y + s), Pos2::new(center.x - s, center.y - s)], Stroke::new(1.5, color));
-                painter.line_segment([Pos2::new(center.x - s, center.y + s), Pos2::new(center.x + s, center.y + s)], Stroke::new(1.5, color));
-                // Candlestick bars
-                painter.line_segment([Pos2::new(center.x - s * 0.5, center.y + s * 0.5), Pos2::new(center.x - s * 0.5, center.y - s * 0.2)], Stroke::new(3.0, color));
-                painter.line_segment([Pos2::new(center.x, center.y + s * 0.5), Pos2::new(center.x, center.y - s * 0.6)], Stroke::new(3.0, color));
-                painter.line_segment([Pos2::new(center.x + s * 0.5, center.y + s * 0.5), Pos2::new(center.x + s * 0.5, center.y)], Stroke::new(3.0, color));
-            }
-            FooterIcon::IcpTokens => {
-                // Coin/token icon - circle with inner detail
-                let s = size * 0.35;
-                painter.circle_stroke(center, s, Stroke::new(2.0, color));
-                // Inner ring
-                painter.circle_stroke(center, s * 0.6, Stroke::new(1.5, color));
-                // Center dot
-                painter.circle_filled(center, s * 0.15, color);
-            }
-        }
+    /// Paint a logo image once it has finished loading.
+    /// Returns false when the caller should fall back to the vector icon.
+    fn paint_icon_image(&mut self, painter: &egui::Painter, path: &str, center: Pos2, size: f32, tint: Color32, time: f64) -> bool {
+        let LogoState::Ready(texture) = self.logos.poll_image(painter.ctx(), &self.assets, path, time) else { return false };
+
+        let rect = Rect::from_center_size(center, Vec2::splat(size * 0.8));
+        let uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+        painter.image(texture.id, rect, uv, tint);
+        true
     }
 }
 
+#[cfg(target_arch = "wasm32")]
 fn open_url(url: &str) {
     if let Some(w) = web_sys::window() {
         let _ = w.open_with_url_and_target(url, "_blank");
     }
 }
 
+/// Look up a query string parameter on the page URL
+#[cfg(target_arch = "wasm32")]
+fn query_param(name: &str) -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    search.trim_start_matches('?')
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == name)
+        .map(|(_, v)| v.to_string())
+}
+
+/// Full URL of the current page
+#[cfg(target_arch = "wasm32")]
+fn page_url() -> String {
+    web_sys::window().and_then(|w| w.location().href().ok()).unwrap_or_default()
+}
+
+/// Content of `<meta name="{name}">` in the page head
+#[cfg(target_arch = "wasm32")]
+fn meta_content(name: &str) -> Option<String> {
+    let doc = web_sys::window()?.document()?;
+    doc.query_selector(&format!("meta[name=\"{name}\"]")).ok()??.get_attribute("content")
+}
+
+#[cfg(target_arch = "wasm32")]
+thread_local! {
+    static ASSET_BASE_OVERRIDE: std::cell::RefCell<Option<String>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Serve logos and fonts from `base` (relative, absolute or `ic://<canister-id>/`),
+/// overriding the `asset-base` meta tag. Takes effect on the next frame, and
+/// fonts that failed from the old base are requested again from the new one.
+/// A base known when the page is served belongs in the meta tag instead, which
+/// applies from the very first request.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn set_asset_base(base: String) {
+    ASSET_BASE_OVERRIDE.with(|o| *o.borrow_mut() = Some(base));
+}
+
+/// Change the max log level at runtime, e.g. `set_log_level("debug")`
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub fn set_log_level(level: &str) {
+    match logging::parse_level(level) {
+        Some(level) => log::set_max_level(level),
+        None => log::warn!("unknown log level {level:?}"),
+    }
+}
+
+pub use snapshot::{Snapshot, DEFAULT_SITE_URL};
+
+/// Log through `env_logger` in native tools, honouring `RUST_LOG`
+#[cfg(not(target_arch = "wasm32"))]
+pub use logging::init as init_logging;
+
+/// Brand font files missing from an asset directory
+#[cfg(not(target_arch = "wasm32"))]
+pub use fonts::missing_faces as missing_brand_fonts;
+#[cfg(not(target_arch = "wasm32"))]
+pub use offscreen::Offscreen;
+#[cfg(not(target_arch = "wasm32"))]
+pub use script::{Script, ScriptError};
+#[cfg(not(target_arch = "wasm32"))]
+pub use social::{SocialCard, CARD_SIZE};
+
+/// Native builds are only used for tests and tooling, which have nowhere to navigate
+#[cfg(not(target_arch = "wasm32"))]
+fn open_url(_url: &str) {}
+
+/// Seconds on a monotonic clock, from `performance.now()` in the browser
+#[cfg(target_arch = "wasm32")]
+fn now() -> f64 {
+    web_sys::window()
+        .and_then(|w| w.performance())
+        .map(|p| p.now() / 1000.0)
+        .unwrap_or(0.0)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now() -> f64 {
+    static EPOCH: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+    EPOCH.get_or_init(std::time::Instant::now).elapsed().as_secs_f64()
+}
+
+/// Widget style for the page, set once since nothing in it changes per frame
+fn install_style(ctx: &egui::Context) {
+    ctx.style_mut(|style| {
+        style.visuals.widgets.noninteractive.bg_fill = Color32::TRANSPARENT;
+        style.visuals.widgets.inactive.bg_fill = Color32::TRANSPARENT;
+        style.spacing.item_spacing = Vec2::new(8.0, 8.0);
+    });
+}
+
+#[cfg(target_arch = "wasm32")]
 impl eframe::App for AlexandriaApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.ui(ctx);
+    }
+}
+
+impl AlexandriaApp {
+    /// Lay out and paint one frame of the page
+    pub fn ui(&mut self, ctx: &egui::Context) {
+        // Named font families only bind from the frame after they're installed
+        if !BrandFonts::ready(ctx) {
+            self.fonts.install(ctx);
+            install_style(ctx);
+            ctx.request_repaint();
+            return;
+        }
+        #[cfg(target_arch = "wasm32")]
+        if let Some(base) = ASSET_BASE_OVERRIDE.with(|o| o.borrow_mut().take()) {
+            self.assets = AssetResolver::new(&page_url(), Some(&base));
+            self.fonts.retry_failed();
+        }
+        self.fonts.poll(ctx, &self.assets);
+        let frame_start = now();
+        self.debug.begin_frame(ctx, frame_start);
+        self.hit_areas.clear();
+
         let screen_rect = ctx.screen_rect();
         let width = screen_rect.width();
-        let typo = Typography::for_width(width);
+        let breakpoint = Breakpoint::for_width(width);
+        let typo = Typography::for_width(width, self.font_scale);
         let colors = self.colors;
         let max_content_width = 1100.0_f32.min(width - 32.0);
+        self.galleys.begin_frame(Environment {
+            width,
+            pixels_per_point: ctx.pixels_per_point(),
+            font_scale: self.font_scale,
+            fonts: self.fonts.generation(),
+            palette: colors,
+        });
 
         // Calculate delta time for smooth animations
-        let current_time = web_sys::window()
-            .and_then(|w| w.performance())
-            .map(|p| p.now() / 1000.0)
-            .unwrap_or(0.0);
+        let current_time = self.visibility.borrow().clock.time(self.simulated_time.unwrap_or_else(now));
         let relative_time = current_time - self.start_time;
-        let dt = (current_time - self.last_frame_time) as f32;
+        // After an idle gap, tweens start from rest at a nominal frame step
+        let continuous = self.repaint.is_continuous();
+        let interval = current_time - self.last_frame_time;
+        let dt = if continuous { interval as f32 } else { 1.0 / 60.0 };
         let dt = dt.clamp(0.001, 0.1); // Clamp to avoid jumps
         self.last_frame_time = current_time;
-
-        let mut style = (*ctx.style()).clone();
-        style.visuals.widgets.noninteractive.bg_fill = Color32::TRANSPARENT;
-        style.visuals.widgets.inactive.bg_fill = Color32::TRANSPARENT;
-        style.spacing.item_spacing = Vec2::new(8.0, 8.0);
-        ctx.set_style(style);
+        self.repaint = RepaintSchedule::new(relative_time);
 
         ctx.layer_painter(egui::LayerId::background()).rect_filled(screen_rect, 0.0, colors.bg_primary);
 
+        // A tap anywhere other than a glossary term dismisses a pinned definition
+        let tapped = ctx.input(|i| i.pointer.any_click());
+        let mut tapped_term = false;
+
         egui::CentralPanel::default()
             .frame(egui::Frame::none().fill(Color32::TRANSPARENT))
             .show(ctx, |ui| {
@@ -599,32 +1370,19 @@ impl eframe::App for AlexandriaApp {
                         let cursor = if self.typewriter.cursor_visible(relative_time) { "|" } else { " " };
 
                         ui.horizontal(|ui| {
-                            let text_galley = ui.fonts(|f| {
-                                f.layout_no_wrap(
-                                    display_text.clone(),
-                                    FontId::new(typo.title_size, FontFamily::Proportional),
-                                    colors.text_primary,
-                                )
-                            });
-                            let cursor_galley = ui.fonts(|f| {
-                                f.layout_no_wrap(
-                                    cursor.to_string(),
-                                    FontId::new(typo.title_size, FontFamily::Proportional),
-                                    colors.text_muted,
-                                )
-                            });
+                            let text_galley = self.galleys.no_wrap(ui, &display_text, typo.title_font(), colors.text_primary);
+                            let cursor_galley = self.galleys.no_wrap(ui, cursor, typo.title_font(), colors.text_muted);
                             let total_width = text_galley.size().x + cursor_galley.size().x;
                             let available = ui.available_width();
                             ui.add_space(((available - total_width) / 2.0).max(0.0));
-                            ui.label(egui::RichText::new(&display_text).size(typo.title_size).color(colors.text_primary));
-                            ui.label(egui::RichText::new(cursor).size(typo.title_size).color(colors.text_muted));
+                            ui.label(text_galley);
+                            ui.label(cursor_galley);
                         });
                         ui.add_space(12.0);
                         ui.label(egui::RichText::new(SUBTITLE_TEXT).size(typo.subtitle_size).color(colors.text_secondary));
                         ui.add_space(40.0);
 
-                        // Request continuous repaint for animation
-                        ctx.request_repaint();
+                        self.repaint.at(self.typewriter.next_wakeup(relative_time));
 
                         // Metrics border
                         let r = ui.available_rect_before_wrap();
@@ -632,216 +1390,114 @@ impl eframe::App for AlexandriaApp {
                         ui.add_space(20.0);
 
                         // Metrics with hover glow
-                        ui.horizontal(|ui| {
-                            let mw = if width < 500.0 { 70.0 } else { 120.0 };
-                            let mh = 60.0;
-                            let sp = (ui.available_width() - METRICS.len() as f32 * mw) / (METRICS.len() + 1) as f32;
-                            for (idx, m) in METRICS.iter().enumerate() {
-                                ui.add_space(sp.max(8.0));
-                                ui.vertical(|ui| {
-                                    ui.set_width(mw);
-                                    ui.set_min_height(mh);
-                                    ui.vertical_centered(|ui| {
-                                        // Update hover state
-                                        let hover_rect = Rect::from_min_size(
-                                            ui.cursor().min,
-                                            Vec2::new(mw, mh)
-                                        );
-                                        let hover_sense = ui.interact(hover_rect, ui.id().with(("metric", idx)), Sense::hover());
-                                        let hover_intensity = self.hover.update_metric(idx, hover_sense.hovered(), dt);
-
-                                        // Draw glow effect behind metric
-                                        if hover_intensity > 0.01 {
-                                            let glow_color = lerp_color(colors.accent_rust, colors.accent_ember, hover_intensity);
-                                            let alpha = (40.0 * hover_intensity) as u8;
-                                            let bg = Color32::from_rgba_unmultiplied(glow_color.r(), glow_color.g(), glow_color.b(), alpha);
-                                            ui.painter().rect_filled(hover_rect.expand(4.0), Rounding::same(8.0), bg);
-                                        }
-
-                                        // Metric value with glow color on hover
-                                        let value_color = if hover_intensity > 0.5 {
-                                            lerp_color(colors.text_primary, colors.accent_ember, (hover_intensity - 0.5) * 2.0)
-                                        } else {
-                                            colors.text_primary
-                                        };
-                                        self.centered_label(ui, m.value, typo.metric_value_size, value_color);
-                                        ui.add_space(4.0);
-                                        ui.label(egui::RichText::new(m.label.to_uppercase()).size(typo.metric_label_size).color(colors.text_muted));
-                                    });
-                                });
-                            }
-                        });
+                        self.metrics_grid(ui, &typo, breakpoint, dt);
                         ui.add_space(20.0);
 
                         let r = ui.available_rect_before_wrap();
                         ui.painter().line_segment([Pos2::new(r.left(), r.top()), Pos2::new(r.right(), r.top())], Stroke::new(1.0, colors.border));
                         ui.add_space(30.0);
 
-                        // Products with cyberpunk hover effects
-                        for (product_idx, p) in PRODUCTS.iter().enumerate() {
-                            ui.scope(|ui| {
-                                let row = ui.vertical(|ui| {
-                                    ui.add_space(16.0);
-
-                                    // Product header with image
-                                    ui.horizontal(|ui| {
-                                        // Product logo - construct full URL
-                                        let img_size = 40.0;
-                                        let full_image_url = format!("{}{}", self.base_url, p.image);
-                                        ui.add(
-                                            egui::Image::new(full_image_url)
-                                                .fit_to_exact_size(Vec2::splat(img_size))
-                                                .rounding(4.0)
-                                        );
-                                        ui.add_space(12.0);
-
-                                        ui.vertical(|ui| {
-                                            ui.label(egui::RichText::new(p.name).size(typo.product_name_size).color(colors.text_primary));
-                                            ui.add_space(4.0);
-                                            ui.horizontal_wrapped(|ui| {
-                                                for t in p.tags {
-                                                    let g = ui.fonts(|f| f.layout_no_wrap(t.to_uppercase(), FontId::new(typo.tag_size, FontFamily::Proportional), colors.tag_text));
-                                                    let sz = g.size() + Vec2::new(10.0, 4.0);
-                                                    let (tr, tag_resp) = ui.allocate_exact_size(sz, Sense::hover());
-
-                                                    // Tag glow on hover
-                                                    let tag_glow = if tag_resp.hovered() { 1.0 } else { 0.0 };
-                                                    if tag_glow > 0.0 {
-                                                        self.draw_tag_glow(ui.painter(), tr, tag_glow, relative_time);
-                                                    }
-
-                                                    ui.painter().rect_filled(tr, Rounding::same(3.0), colors.tag_bg);
-                                                    ui.painter().galley(tr.min + Vec2::new(5.0, 2.0), g, colors.tag_text);
-                                                }
-                                            });
-                                        });
+                        // Products in a responsive grid with cyberpunk hover effects
+                        let cols = breakpoint.product_columns();
+                        let gap = 24.0;
+                        let col_width = (ui.available_width() - gap * (cols - 1) as f32) / cols as f32;
+                        // Cards in a row share the tallest one's height from the previous frame
+                        if self.card_row_heights.0 != cols {
+                            self.card_row_heights = (cols, Vec::new());
+                        }
+                        self.card_row_heights.1.resize(PRODUCTS.len().div_ceil(cols), 0.0);
+                        for row_idx in 0..PRODUCTS.len().div_ceil(cols) {
+                            let row_height = self.card_row_heights.1[row_idx];
+                            let mut tallest: f32 = 0.0;
+                            ui.horizontal_top(|ui| {
+                                ui.spacing_mut().item_spacing.x = gap;
+                                for product_idx in row_idx * cols..((row_idx + 1) * cols).min(PRODUCTS.len()) {
+                                    ui.allocate_ui_with_layout(Vec2::new(col_width, 0.0), egui::Layout::top_down(egui::Align::Min), |ui| {
+                                        ui.set_width(col_width);
+                                        let (height, tapped) = self.product_card(ui, product_idx, &typo, relative_time, dt, row_height);
+                                        tallest = tallest.max(height);
+                                        tapped_term |= tapped;
                                     });
-
-                                    ui.add_space(12.0);
-                                    ui.label(egui::RichText::new(p.tagline).size(typo.tagline_size).color(colors.text_primary));
-                                    ui.add_space(8.0);
-                                    ui.label(egui::RichText::new(p.description).size(typo.description_size).color(colors.text_secondary));
-                                    ui.add_space(16.0);
-
-                                    let br = ui.available_rect_before_wrap();
-                                    ui.painter().line_segment([Pos2::new(br.left(), br.top()), Pos2::new(br.right(), br.top())], Stroke::new(1.0, colors.border));
-                                });
-
-                                let card_rect = row.response.rect;
-                                let click = ui.interact(card_rect, ui.id().with(p.name), Sense::click());
-                                let is_hovered = click.hovered();
-
-                                // Update hover animation state
-                                let hover_intensity = self.hover.update_hover(product_idx, is_hovered, dt);
-                                let scanline_pos = self.hover.update_scanline(product_idx, is_hovered, dt);
-
-                                // Draw cyberpunk effects (in reverse order for proper layering)
-                                // 1. Background glow
-                                self.draw_hover_bg(ui.painter(), card_rect, hover_intensity);
-
-                                // 2. Glowing border
-                                self.draw_glow_border(ui.painter(), card_rect, hover_intensity, relative_time);
-
-                                // 3. Animated scanline sweep
-                                self.draw_scanline(ui.painter(), card_rect, scanline_pos, hover_intensity);
-
-                                if click.clicked() { open_url(p.url); }
-                                if is_hovered { ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand); }
+                                }
                             });
+                            self.card_row_heights.1[row_idx] = tallest;
                         }
 
                         ui.add_space(40.0);
 
                         // Footer with cyberpunk glow and rusty tooltips
-                        ui.horizontal(|ui| {
-                            let isz = 28.0;
-                            let tw = FOOTER_LINKS.len() as f32 * (isz + 20.0);
-                            ui.add_space(((ui.available_width() - tw) / 2.0).max(0.0));
-                            for l in FOOTER_LINKS {
-                                let (r, rsp) = ui.allocate_exact_size(Vec2::splat(isz + 8.0), Sense::click());
-                                let hov = rsp.hovered();
-
-                                // Cyberpunk glow on hover
-                                if hov {
-                                    // Animated glow color
-                                    let phase = (relative_time * 4.0) as f32;
-                                    let glow_t = phase.sin() * 0.5 + 0.5;
-                                    let glow_color = lerp_color(colors.accent_copper, colors.accent_ember, glow_t);
-
-                                    // Multiple glow layers
-                                    for (alpha, expand) in [(30, 12.0), (50, 8.0), (80, 4.0)] {
-                                        let glow = Color32::from_rgba_unmultiplied(
-                                            glow_color.r(), glow_color.g(), glow_color.b(), alpha
-                                        );
-                                        ui.painter().circle_stroke(r.center(), (isz / 2.0) + expand, Stroke::new(2.0, glow));
-                                    }
-                                }
-
-                                let col = if hov { colors.accent_ember } else { colors.text_muted };
-                                let sc = if hov { 1.15 } else { 1.0 };
-                                self.render_footer_icon(ui.painter(), l.icon, r.center(), isz * sc, col);
-
-                                // Rusty tooltip on hover
-                                rsp.clone().on_hover_ui_at_pointer(|ui| {
-                                    // Style the tooltip with rust theme
-                                    let tooltip_frame = egui::Frame::none()
-                                        .fill(Color32::from_rgb(20, 20, 20))
-                                        .stroke(Stroke::new(1.5, colors.accent_rust))
-                                        .rounding(Rounding::same(4.0))
-                                        .inner_margin(egui::Margin::symmetric(12.0, 8.0));
-
-                                    tooltip_frame.show(ui, |ui| {
-                                        ui.label(
-                                            egui::RichText::new(l.title)
-                                                .size(13.0)
-                                                .color(colors.accent_ember)
-                                        );
-                                    });
-                                });
+                        self.footer(ui, &typo, breakpoint, relative_time, dt);
 
-                                if rsp.clicked() { open_url(l.url); }
-                                if hov { ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand); }
-                                ui.add_space(12.0);
-                            }
+                        ui.add_space(24.0);
+                        ui.vertical_centered(|ui| {
+                            ui.label(egui::RichText::new(COPYRIGHT_TEXT).size(typo.tag_size + 2.0).color(colors.text_muted));
                         });
 
                         ui.add_space(60.0);
                     });
                 });
             });
-    }
-}
 
-// Logging helper for debugging
-macro_rules! console_log {
-    ($($arg:tt)*) => {{
-        web_sys::console::log_1(&format!("[RUST] {}", format!($($arg)*)).into());
-    }};
+        if tapped && !tapped_term {
+            self.pinned_glossary = None;
+        }
+
+        if self.hover.take_animating() {
+            self.repaint.asap();
+        }
+        // Visibility callbacks request a repaint when the page is seen again
+        if !self.visibility.borrow().clock.is_paused() {
+            self.repaint.apply(ctx);
+        }
+
+        // Judge quality on whole frames, including tessellation and the GPU,
+        // while animating. Offscreen runs on a simulated clock stay at full quality.
+        if continuous && self.simulated_time.is_none() {
+            self.quality.record(relative_time, interval);
+        }
+        let cpu_time = now() - frame_start;
+        let readout = Readout { breakpoint, quality: self.quality.level(), hover: self.hover.active() };
+        self.debug.end_frame(ctx, cpu_time, readout);
+    }
 }
 
+#[cfg(target_arch = "wasm32")]
 #[wasm_bindgen(start)]
 pub fn main() {
-    console_error_panic_hook::set_once();
-    console_log!("WASM module loaded successfully");
+    startup::install_panic_hook();
+    // `?log=debug` raises the level from the first message on
+    logging::init(query_param("log").and_then(|v| logging::parse_level(&v)).unwrap_or(logging::DEFAULT_LEVEL));
+    log::debug!("WASM module loaded");
 
     wasm_bindgen_futures::spawn_local(async {
-        console_log!("Starting application initialization...");
-
-        let doc = web_sys::window().expect("No window").document().expect("No document");
-        console_log!("Got document");
-
-        let canvas = doc.get_element_by_id("canvas").expect("No canvas").dyn_into::<web_sys::HtmlCanvasElement>().expect("Not a canvas");
-        console_log!("Got canvas element");
-
-        console_log!("Starting eframe WebRunner...");
-        let result = eframe::WebRunner::new().start(canvas, eframe::WebOptions::default(), Box::new(|cc| {
-            console_log!("Creating AlexandriaApp...");
-            Ok(Box::new(AlexandriaApp::new(cc)))
-        })).await;
-
-        match result {
-            Ok(_) => console_log!("Application started successfully"),
-            Err(e) => console_log!("Failed to start application: {:?}", e),
+        match start().await {
+            Ok(()) => log::info!("application started"),
+            Err(err) => {
+                log::error!("startup failed: {err}");
+                startup::show_fallback(&err);
+            }
         }
     });
 }
+
+/// Find the canvas and start the app on it
+#[cfg(target_arch = "wasm32")]
+async fn start() -> Result<(), startup::StartupError> {
+    use startup::StartupError;
+
+    let doc = web_sys::window().ok_or(StartupError::NoWindow)?.document().ok_or(StartupError::NoDocument)?;
+    let canvas = doc
+        .get_element_by_id("canvas")
+        .ok_or(StartupError::NoCanvas)?
+        .dyn_into::<web_sys::HtmlCanvasElement>()
+        .map_err(|_| StartupError::NotACanvas)?;
+    log::debug!("got canvas element, starting eframe WebRunner");
+
+    eframe::WebRunner::new()
+        .start(canvas, eframe::WebOptions::default(), Box::new(|cc| {
+            log::debug!("creating AlexandriaApp");
+            Ok(Box::new(AlexandriaApp::new(cc)))
+        }))
+        .await
+        .map_err(|e| StartupError::from_runner(e.as_string().unwrap_or_else(|| format!("{e:?}"))))
+}
@@ -0,0 +1,90 @@
+//! Backends for the `log` crate
+//!
+//! In the browser each record goes to the console method for its level, so
+//! devtools' level filter works on it. The max level starts at
+//! [`DEFAULT_LEVEL`], can be set with `?log=debug`, and can be changed later
+//! from JS with `set_log_level`. Native builds log through `env_logger`, which
+//! reads `RUST_LOG` as usual.
+
+use log::LevelFilter;
+
+/// Max level when nothing else is configured
+pub(crate) const DEFAULT_LEVEL: LevelFilter = if cfg!(debug_assertions) { LevelFilter::Debug } else { LevelFilter::Info };
+
+/// Level filter from a name like `warn` or `DEBUG`, or `None` if unrecognized
+#[cfg(any(test, target_arch = "wasm32"))]
+pub(crate) fn parse_level(name: &str) -> Option<LevelFilter> {
+    name.trim().parse().ok()
+}
+
+/// Record target without this crate's prefix, e.g. `logos` for `alexandria_landing::logos`
+#[cfg(any(test, target_arch = "wasm32"))]
+fn short_target(target: &str) -> &str {
+    match target.strip_prefix(env!("CARGO_CRATE_NAME")) {
+        Some("") => "",
+        Some(rest) => rest.strip_prefix("::").unwrap_or(target),
+        None => target,
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+struct ConsoleLogger;
+
+#[cfg(target_arch = "wasm32")]
+impl log::Log for ConsoleLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let target = short_target(record.target());
+        let message = if target.is_empty() { format!("{}", record.args()) } else { format!("[{target}] {}", record.args()) };
+        let message = wasm_bindgen::JsValue::from(message);
+        match record.level() {
+            log::Level::Error => web_sys::console::error_1(&message),
+            log::Level::Warn => web_sys::console::warn_1(&message),
+            log::Level::Info => web_sys::console::info_1(&message),
+            log::Level::Debug | log::Level::Trace => web_sys::console::debug_1(&message),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Install the console logger at `level`. Calling it again only changes the level.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn init(level: LevelFilter) {
+    static LOGGER: ConsoleLogger = ConsoleLogger;
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(level);
+}
+
+/// Install `env_logger` at [`DEFAULT_LEVEL`] unless `RUST_LOG` says otherwise.
+/// Does nothing if a logger is already installed.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn init() {
+    let _ = env_logger::Builder::new().filter_level(DEFAULT_LEVEL).parse_default_env().try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_names() {
+        assert_eq!(parse_level("warn"), Some(LevelFilter::Warn));
+        assert_eq!(parse_level(" DEBUG "), Some(LevelFilter::Debug));
+        assert_eq!(parse_level("off"), Some(LevelFilter::Off));
+        assert_eq!(parse_level("loud"), None);
+    }
+
+    #[test]
+    fn targets_drop_the_crate_prefix() {
+        assert_eq!(short_target("alexandria_landing::logos"), "logos");
+        assert_eq!(short_target("alexandria_landing"), "");
+        assert_eq!(short_target("eframe::web"), "eframe::web");
+    }
+}
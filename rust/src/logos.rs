@@ -0,0 +1,317 @@
+//! Product logo loading: skeleton while loading, monogram on failure
+//!
+//! Logos come from the canister over HTTP, so a slow or failed request must not
+//! leave a hole in the card header. [`LogoLoader`] remembers failures per URI,
+//! retries them with exponential backoff, and logs each failed attempt. The
+//! footer's exchange logos go through it too.
+//!
+//! A logo can ship several renditions ([`ImageVariants`]). The loader asks for
+//! the best one for the pixel size the logo is drawn at, which folds in the
+//! display's DPR and browser zoom, so moving the window to a denser screen
+//! swaps in the sharper file while the old one stays up until it arrives. The
+//! high-res file is only fetched once the 1x one would be upscaled.
+//!
+//! With the `embed-logos` feature the 1x and svg renditions are compiled in and
+//! registered as `bytes://` URIs, so cards paint with logos on the first frame.
+//! Only variants that would look sharper than the embedded one are fetched.
+
+use std::collections::HashMap;
+
+use egui::load::{SizedTexture, TexturePoll};
+
+use crate::assets::AssetResolver;
+
+/// Retries after the first failure before a logo is given up on
+pub(crate) const MAX_RETRIES: u32 = 4;
+
+/// Delay before the first retry, doubled for each one after
+const BASE_BACKOFF: f64 = 1.0;
+
+/// Logos compiled into the binary, by their path relative to the site root
+#[cfg(feature = "embed-logos")]
+pub(crate) const EMBEDDED: &[(&str, &[u8])] = &[
+    ("/logos/projects/alex.png", include_bytes!("../public/logos/projects/alex.png")),
+    ("/logos/projects/cyclescan.png", include_bytes!("../public/logos/projects/cyclescan.png")),
+    ("/logos/projects/daopad_logo.png", include_bytes!("../public/logos/projects/daopad_logo.png")),
+    ("/logos/projects/ipg.png", include_bytes!("../public/logos/projects/ipg.png")),
+    ("/logos/projects/kong_locker.png", include_bytes!("../public/logos/projects/kong_locker.png")),
+    ("/logos/projects/lbry_fun.svg", include_bytes!("../public/logos/projects/lbry_fun.svg")),
+    ("/logos/projects/openhouse.png", include_bytes!("../public/logos/projects/openhouse.png")),
+];
+
+#[cfg(not(feature = "embed-logos"))]
+pub(crate) const EMBEDDED: &[(&str, &[u8])] = &[];
+
+/// Upper bound on embedded logo bytes. Raise it deliberately, not by accident.
+pub(crate) const EMBED_BUDGET: usize = 400 * 1024;
+
+/// Total size of the embedded logos
+pub(crate) const fn embedded_size() -> usize {
+    let mut total = 0;
+    let mut i = 0;
+    while i < EMBEDDED.len() {
+        total += EMBEDDED[i].1.len();
+        i += 1;
+    }
+    total
+}
+
+const _: () = assert!(embedded_size() <= EMBED_BUDGET, "embedded logos exceed EMBED_BUDGET");
+
+/// Stable URI an embedded logo is registered under
+fn embedded_uri(path: &str) -> String {
+    format!("bytes:/{path}")
+}
+
+fn is_embedded(path: &str) -> bool {
+    EMBEDDED.iter().any(|(p, _)| *p == path)
+}
+
+/// Renditions of one logo, relative to the site root
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct ImageVariants {
+    pub x1: Option<&'static str>,
+    /// Width of the 1x bitmap in pixels, the most it can be drawn at sharply
+    pub x1_width: u32,
+    pub x2: Option<&'static str>,
+    pub svg: Option<&'static str>,
+}
+
+impl ImageVariants {
+    /// A bitmap `width` pixels wide
+    pub const fn png(x1: &'static str, width: u32) -> Self {
+        Self { x1: Some(x1), x1_width: width, x2: None, svg: None }
+    }
+
+    pub const fn svg(path: &'static str) -> Self {
+        Self { x1: None, x1_width: 0, x2: None, svg: Some(path) }
+    }
+
+    pub const fn with_2x(self, x2: &'static str) -> Self {
+        Self { x2: Some(x2), ..self }
+    }
+
+    /// Variants in order of preference for a logo drawn `pixels` wide. Vector
+    /// art wins at any size; the 2x bitmap only beats 1x when 1x would be
+    /// upscaled.
+    pub fn preferred(&self, pixels: f32) -> impl Iterator<Item = &'static str> {
+        let bitmaps = if pixels > self.x1_width as f32 { [self.x2, self.x1] } else { [self.x1, self.x2] };
+        std::iter::once(self.svg).chain(bitmaps).flatten()
+    }
+}
+
+/// What to draw in a logo's slot this frame
+pub(crate) enum LogoState {
+    Loading,
+    Ready(SizedTexture),
+    /// Failed, either for good or while waiting for the next retry
+    Failed,
+}
+
+struct Failure {
+    count: u32,
+    retry_at: f64,
+}
+
+#[derive(Default)]
+pub(crate) struct LogoLoader {
+    failures: HashMap<String, Failure>,
+    /// Last texture drawn for each logo, kept up while a sharper variant loads
+    shown: HashMap<String, SizedTexture>,
+    registered: bool,
+}
+
+/// Seconds to wait before retrying after `failures` consecutive failures
+pub(crate) fn backoff(failures: u32) -> f64 {
+    BASE_BACKOFF * 2f64.powi(failures.saturating_sub(1) as i32)
+}
+
+impl LogoLoader {
+    /// Poll the best variant for a logo drawn `size` points wide. Variants that
+    /// have failed, for good or until their next retry, are skipped in favour
+    /// of the next best one.
+    pub fn poll_variants(&mut self, ctx: &egui::Context, assets: &AssetResolver, variants: &ImageVariants, size: f32, now: f64) -> LogoState {
+        if !self.registered {
+            self.register_embedded(ctx);
+        }
+        let ppp = ctx.pixels_per_point();
+        let pixels = size * ppp;
+        let key = variants.preferred(0.0).next().unwrap_or_default();
+        let embedded = variants.preferred(pixels).find(|path| is_embedded(path));
+        // Variants ranked below the embedded one are never worth a request
+        let Some(path) = variants
+            .preferred(pixels)
+            .take_while(|path| Some(*path) != embedded)
+            .find(|path| !self.failed(&assets.resolve(path), now))
+            .or(embedded)
+        else {
+            return self.shown.get(key).map_or(LogoState::Failed, |&texture| LogoState::Ready(texture));
+        };
+        let uri = if Some(path) == embedded { embedded_uri(path) } else { assets.resolve(path) };
+        // Rasterize vector art at the exact pixel size it will be shown at
+        let hint = if Some(path) == variants.svg {
+            let px = pixels.round() as u32;
+            egui::SizeHint::Size(px, px)
+        } else {
+            egui::SizeHint::Scale(ppp.into())
+        };
+
+        match self.poll(ctx, &uri, hint, now) {
+            LogoState::Ready(texture) => {
+                self.shown.insert(key.to_owned(), texture);
+                LogoState::Ready(texture)
+            }
+            state => match (self.shown.get(key), embedded) {
+                (Some(&texture), _) => LogoState::Ready(texture),
+                // Upgrading from an embedded logo that hasn't been shown yet
+                (None, Some(path)) => self.poll(ctx, &embedded_uri(path), egui::SizeHint::Scale(ppp.into()), now),
+                (None, None) => state,
+            },
+        }
+    }
+
+    fn register_embedded(&mut self, ctx: &egui::Context) {
+        for (path, bytes) in EMBEDDED {
+            ctx.include_bytes(embedded_uri(path), *bytes);
+        }
+        if !EMBEDDED.is_empty() {
+            log::info!("embedded {} logos: {} KiB of {} KiB budget", EMBEDDED.len(), embedded_size() / 1024, EMBED_BUDGET / 1024);
+        }
+        self.registered = true;
+    }
+
+    /// Whether `uri` has failed for good or is waiting for its next retry
+    fn failed(&self, uri: &str, now: f64) -> bool {
+        self.failures.get(uri).is_some_and(|f| f.count > MAX_RETRIES || now < f.retry_at)
+    }
+
+    /// Poll a single image, such as an exchange logo, at the display's density,
+    /// with the same retries and logging as product logos
+    pub fn poll_image(&mut self, ctx: &egui::Context, assets: &AssetResolver, path: &str, now: f64) -> LogoState {
+        self.poll(ctx, &assets.resolve(path), egui::SizeHint::Scale(ctx.pixels_per_point().into()), now)
+    }
+
+    /// Poll the texture for `uri` at time `now`, scheduling a retry if it failed
+    fn poll(&mut self, ctx: &egui::Context, uri: &str, hint: egui::SizeHint, now: f64) -> LogoState {
+        if self.failed(uri, now) {
+            return LogoState::Failed;
+        }
+
+        match ctx.try_load_texture(uri, egui::TextureOptions::LINEAR, hint) {
+            Ok(TexturePoll::Ready { texture }) => {
+                self.failures.remove(uri);
+                LogoState::Ready(texture)
+            }
+            // A retry in flight keeps the monogram rather than flashing the skeleton
+            Ok(TexturePoll::Pending { .. }) if self.failures.contains_key(uri) => LogoState::Failed,
+            Ok(TexturePoll::Pending { .. }) => LogoState::Loading,
+            Err(err) => {
+                let failure = self.failures.entry(uri.to_owned()).or_insert(Failure { count: 0, retry_at: 0.0 });
+                failure.count += 1;
+                if failure.count > MAX_RETRIES {
+                    log::error!("logo {uri} failed to load, giving up after {} attempts: {err}", failure.count);
+                } else {
+                    let delay = backoff(failure.count);
+                    log::warn!("logo {uri} failed to load (attempt {}), retrying in {delay}s: {err}", failure.count);
+                    failure.retry_at = now + delay;
+                    // Drop the cached error so the next poll issues a fresh request
+                    ctx.forget_image(uri);
+                    ctx.request_repaint_after(std::time::Duration::from_secs_f64(delay));
+                }
+                LogoState::Failed
+            }
+        }
+    }
+}
+
+/// Up to two initials for a monogram tile, taken from word starts including
+/// camel-case humps (`CycleScan` → `CS`, `lbry.app` → `LA`)
+pub(crate) fn initials(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let starts: String = chars
+        .iter()
+        .enumerate()
+        .filter(|&(i, c)| {
+            let prev = i.checked_sub(1).map(|p| chars[p]);
+            let next = chars.get(i + 1);
+            c.is_alphanumeric()
+                && match prev {
+                    None => true,
+                    Some(p) if !p.is_alphanumeric() => true,
+                    Some(p) => c.is_uppercase() && (p.is_lowercase() || next.is_some_and(|n| n.is_lowercase())),
+                }
+        })
+        .map(|(_, c)| *c)
+        .take(2)
+        .collect();
+    if starts.chars().count() >= 2 {
+        return starts.to_uppercase();
+    }
+    // A single word without humps: use its first two letters
+    name.chars().filter(|c| c.is_alphanumeric()).take(2).collect::<String>().to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles() {
+        assert_eq!(backoff(1), 1.0);
+        assert_eq!(backoff(2), 2.0);
+        assert_eq!(backoff(4), 8.0);
+    }
+
+    #[test]
+    fn high_res_only_when_1x_would_upscale() {
+        let both = ImageVariants::png("/a.png", 200).with_2x("/a@2x.png");
+        assert_eq!(both.preferred(80.0).collect::<Vec<_>>(), ["/a.png", "/a@2x.png"]);
+        assert_eq!(both.preferred(200.0).next(), Some("/a.png"));
+        assert_eq!(both.preferred(320.0).collect::<Vec<_>>(), ["/a@2x.png", "/a.png"]);
+    }
+
+    #[test]
+    fn svg_is_preferred_at_any_size() {
+        let svg = ImageVariants { svg: Some("/a.svg"), ..ImageVariants::png("/a.png", 200) };
+        assert_eq!(svg.preferred(80.0).next(), Some("/a.svg"));
+        assert_eq!(svg.preferred(800.0).next(), Some("/a.svg"));
+        assert_eq!(ImageVariants::svg("/b.svg").preferred(160.0).collect::<Vec<_>>(), ["/b.svg"]);
+    }
+
+    #[test]
+    fn single_variant_is_used_everywhere() {
+        let one = ImageVariants::png("/a.png", 200);
+        assert_eq!(one.preferred(80.0).collect::<Vec<_>>(), ["/a.png"]);
+        assert_eq!(one.preferred(800.0).collect::<Vec<_>>(), ["/a.png"]);
+        assert_eq!(ImageVariants::default().preferred(80.0).next(), None);
+    }
+
+    #[test]
+    fn variants_waiting_to_retry_are_skipped() {
+        let ctx = egui::Context::default();
+        egui_extras::install_image_loaders(&ctx);
+        let assets = AssetResolver::new("https://example.com/", None);
+        let mut png = Vec::new();
+        image::RgbaImage::new(1, 1).write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png).unwrap();
+        // Only the 1x file is there; the 2x one fails
+        ctx.include_bytes(assets.resolve("/a.png"), png);
+        let variants = ImageVariants::png("/a.png", 1).with_2x("/a@2x.png");
+        let mut loader = LogoLoader::default();
+
+        assert!(matches!(loader.poll_variants(&ctx, &assets, &variants, 40.0, 0.0), LogoState::Failed));
+        assert!(matches!(loader.poll_variants(&ctx, &assets, &variants, 40.0, 0.1), LogoState::Ready(_)));
+        // The 1x logo stays up while the 2x one is retried
+        assert!(matches!(loader.poll_variants(&ctx, &assets, &variants, 40.0, backoff(1)), LogoState::Ready(_)));
+    }
+
+    #[test]
+    fn initials_from_words_and_humps() {
+        assert_eq!(initials("Caffeine Launcher"), "CL");
+        assert_eq!(initials("CycleScan"), "CS");
+        assert_eq!(initials("DAOPad"), "DP");
+        assert_eq!(initials("lbry.app"), "LA");
+        assert_eq!(initials("IPG"), "IP");
+        assert_eq!(initials("X"), "X");
+        assert_eq!(initials(""), "");
+    }
+}
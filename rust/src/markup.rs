@@ -0,0 +1,299 @@
+//! Minimal inline markup for product copy
+//!
+//! Supports `**bold**`, `*italic*`, `$TOKEN` symbols and `[text](url)` links.
+//! Anything that doesn't form a complete construct is kept as literal text,
+//! and `\` escapes the next markup character.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum SpanKind<'a> {
+    Text,
+    /// A `$TOKEN` symbol, including the leading `$`
+    Token,
+    /// Link text with its target URL
+    Link(&'a str),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Span<'a> {
+    pub text: &'a str,
+    pub kind: SpanKind<'a>,
+    pub bold: bool,
+    pub italic: bool,
+}
+
+const ESCAPABLE: &[u8] = b"\\*$[]()";
+
+/// Parse markup into styled spans. Never fails: malformed markup is literal text.
+pub(crate) fn parse<'a>(src: &'a str) -> Vec<Span<'a>> {
+    let bytes = src.as_bytes();
+    let mut spans = Vec::new();
+    let (mut bold, mut italic) = (false, false);
+    let mut run_start = 0;
+    let mut i = 0;
+
+    // Markup characters are all ASCII, so byte offsets at them are char boundaries
+    let flush = |spans: &mut Vec<Span<'a>>, from: usize, to: usize, bold: bool, italic: bool| {
+        if from < to {
+            spans.push(Span { text: &src[from..to], kind: SpanKind::Text, bold, italic });
+        }
+    };
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if bytes.get(i + 1).is_some_and(|c| ESCAPABLE.contains(c)) => {
+                flush(&mut spans, run_start, i, bold, italic);
+                // The escaped character starts the next run and is skipped by the scanner
+                run_start = i + 1;
+                i += 2;
+            }
+            b'*' if bytes.get(i + 1) == Some(&b'*') => {
+                let toggles = if bold { closes(bytes, i) } else { opens(src, i, 2, "**") };
+                if toggles {
+                    flush(&mut spans, run_start, i, bold, italic);
+                    bold = !bold;
+                    run_start = i + 2;
+                }
+                i += 2;
+            }
+            b'*' => {
+                let toggles = if italic { closes(bytes, i) } else { opens(src, i, 1, "*") };
+                if toggles {
+                    flush(&mut spans, run_start, i, bold, italic);
+                    italic = !italic;
+                    run_start = i + 1;
+                }
+                i += 1;
+            }
+            b'$' if bytes.get(i + 1).is_some_and(u8::is_ascii_uppercase) => {
+                let len = 1 + bytes[i + 1..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+                    .count();
+                flush(&mut spans, run_start, i, bold, italic);
+                spans.push(Span { text: &src[i..i + len], kind: SpanKind::Token, bold, italic });
+                i += len;
+                run_start = i;
+            }
+            b'[' => match link_at(src, i) {
+                Some((text, url, end)) => {
+                    flush(&mut spans, run_start, i, bold, italic);
+                    spans.push(Span { text, kind: SpanKind::Link(url), bold, italic });
+                    i = end;
+                    run_start = i;
+                }
+                None => i += 1,
+            },
+            _ => i += 1,
+        }
+    }
+
+    flush(&mut spans, run_start, bytes.len(), bold, italic);
+    spans
+}
+
+/// An opening delimiter must be followed by text and have a matching closer
+/// later on. Escaped characters are skipped as in [`parse`], so an escaped
+/// delimiter can't close.
+fn opens(src: &str, i: usize, len: usize, delim: &str) -> bool {
+    let bytes = src.as_bytes();
+    let Some(first) = src[i + len..].chars().next() else { return false };
+    if first.is_whitespace() || first == '*' {
+        return false;
+    }
+    let mut j = i + len;
+    while j < bytes.len() {
+        match bytes[j] {
+            b'\\' if bytes.get(j + 1).is_some_and(|c| ESCAPABLE.contains(c)) => j += 2,
+            b'*' if bytes[j..].starts_with(delim.as_bytes()) => {
+                // A single `*` closer must not be half of a `**`
+                let standalone = delim.len() == 2 || (bytes.get(j + 1) != Some(&b'*') && bytes[j - 1] != b'*');
+                if standalone && closes(bytes, j) {
+                    return true;
+                }
+                j += delim.len();
+            }
+            _ => j += 1,
+        }
+    }
+    false
+}
+
+/// A closing delimiter must directly follow text
+fn closes(bytes: &[u8], i: usize) -> bool {
+    i > 0 && !bytes[i - 1].is_ascii_whitespace()
+}
+
+/// Match `[text](url)` at `i`, returning the text, url and end offset
+fn link_at(src: &str, i: usize) -> Option<(&str, &str, usize)> {
+    let close = i + 1 + src[i + 1..].find(']')?;
+    let text = &src[i + 1..close];
+    if text.is_empty() || text.contains('[') {
+        return None;
+    }
+    let rest = src[close + 1..].strip_prefix('(')?;
+    let url_len = rest.find(')')?;
+    let url = &rest[..url_len];
+    if url.is_empty() || url.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((text, url, close + 2 + url_len + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(src: &str) -> String {
+        parse(src).iter().map(|s| s.text).collect()
+    }
+
+    fn span(text: &str) -> Span<'_> {
+        Span { text, kind: SpanKind::Text, bold: false, italic: false }
+    }
+
+    #[test]
+    fn plain_text_is_one_span() {
+        assert_eq!(parse("Just copy — no markup → here"), vec![span("Just copy — no markup → here")]);
+    }
+
+    #[test]
+    fn empty_input_has_no_spans() {
+        assert!(parse("").is_empty());
+    }
+
+    #[test]
+    fn bold_and_italic() {
+        assert_eq!(
+            parse("a **b** *c* d"),
+            vec![
+                span("a "),
+                Span { bold: true, ..span("b") },
+                span(" "),
+                Span { italic: true, ..span("c") },
+                span(" d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn italic_inside_bold() {
+        assert_eq!(
+            parse("**a *b* c**"),
+            vec![
+                Span { bold: true, ..span("a ") },
+                Span { bold: true, italic: true, ..span("b") },
+                Span { bold: true, ..span(" c") },
+            ]
+        );
+    }
+
+    #[test]
+    fn unclosed_emphasis_is_literal() {
+        assert_eq!(parse("**never closed"), vec![span("**never closed")]);
+        assert_eq!(parse("*never closed"), vec![span("*never closed")]);
+        assert_eq!(text("**bold *then italic"), "**bold *then italic");
+    }
+
+    #[test]
+    fn spaced_asterisks_are_literal() {
+        assert_eq!(parse("2 * 3 * 4"), vec![span("2 * 3 * 4")]);
+        assert_eq!(parse("* list item"), vec![span("* list item")]);
+    }
+
+    #[test]
+    fn lone_delimiters_are_literal() {
+        assert_eq!(text("*"), "*");
+        assert_eq!(text("**"), "**");
+        assert_eq!(text("****"), "****");
+        assert_eq!(text("a*"), "a*");
+    }
+
+    #[test]
+    fn tokens() {
+        assert_eq!(
+            parse("Stake $ALEX, burn $LBRY2."),
+            vec![
+                span("Stake "),
+                Span { kind: SpanKind::Token, ..span("$ALEX") },
+                span(", burn "),
+                Span { kind: SpanKind::Token, ..span("$LBRY2") },
+                span("."),
+            ]
+        );
+    }
+
+    #[test]
+    fn non_tokens_are_literal() {
+        assert_eq!(parse("costs $5"), vec![span("costs $5")]);
+        assert_eq!(parse("$alex"), vec![span("$alex")]);
+        assert_eq!(parse("ends with $"), vec![span("ends with $")]);
+    }
+
+    #[test]
+    fn links() {
+        assert_eq!(
+            parse("see [the docs](https://lbry.app/info) now"),
+            vec![
+                span("see "),
+                Span { kind: SpanKind::Link("https://lbry.app/info"), ..span("the docs") },
+                span(" now"),
+            ]
+        );
+    }
+
+    #[test]
+    fn emphasis_applies_to_links() {
+        assert_eq!(
+            parse("**[bold link](u)**"),
+            vec![Span { kind: SpanKind::Link("u"), bold: true, ..span("bold link") }]
+        );
+    }
+
+    #[test]
+    fn malformed_links_are_literal() {
+        for src in [
+            "[text]",
+            "[text] (url)",
+            "[text](",
+            "[text](url",
+            "[](url)",
+            "[text]()",
+            "[text](has space)",
+            "[unclosed",
+        ] {
+            assert_eq!(text(src), src, "{src}");
+            assert!(parse(src).iter().all(|s| s.kind == SpanKind::Text), "{src}");
+        }
+    }
+
+    #[test]
+    fn brackets_after_failed_link_still_parse() {
+        assert_eq!(
+            parse("[x [y](u)"),
+            vec![span("[x "), Span { kind: SpanKind::Link("u"), ..span("y") }]
+        );
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(text(r"\*not italic\*"), "*not italic*");
+        assert!(parse(r"\*not italic\*").iter().all(|s| !s.italic));
+        assert_eq!(text(r"\$ALEX"), "$ALEX");
+        assert!(parse(r"\$ALEX").iter().all(|s| s.kind == SpanKind::Text));
+        assert_eq!(text(r"\[a](b)"), "[a](b)");
+        assert_eq!(text(r"back\\slash"), r"back\slash");
+        assert_eq!(text(r"keep \n as is"), r"keep \n as is");
+        assert_eq!(text("trailing \\"), "trailing \\");
+    }
+
+    #[test]
+    fn escaped_delimiters_dont_close() {
+        assert_eq!(parse(r"**a\**"), vec![span("**a"), span("**")]);
+        assert_eq!(parse(r"*a\*"), vec![span("*a"), span("*")]);
+        // A real closer later on still closes
+        assert_eq!(text(r"**a\** b**"), "a** b");
+        assert!(parse(r"**a\** b**").iter().all(|s| s.bold));
+        // An escaped backslash doesn't escape the delimiter after it
+        assert_eq!(parse(r"*a\\*"), vec![Span { italic: true, ..span("a") }, Span { italic: true, ..span(r"\") }]);
+    }
+}
@@ -0,0 +1,353 @@
+//! Rendering frames offscreen on the CPU, for native tools
+//!
+//! [`Offscreen`] drives an `egui::Context` with synthetic input, keeps its
+//! textures up to date from each frame's deltas, and rasterizes the tessellated
+//! meshes into an image the way egui's GL backend blends them: premultiplied
+//! colors in gamma space, vertex color times texel. Antialiasing comes from
+//! egui's feathered edges, so coverage is a plain point sample at each pixel
+//! center. Textures shrunk a lot (a 1024px logo drawn at 160px) are box
+//! filtered over their footprint instead of aliasing.
+//!
+//! Site assets are registered from a directory with [`Offscreen::load_assets`],
+//! so logos and fonts resolve synchronously on the first frame that asks.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+use egui::epaint::{ClippedPrimitive, ImageData, ImageDelta, Primitive, Vertex};
+use egui::{Color32, Pos2, Rect, TextureId, Vec2, ViewportId};
+
+/// Most bilinear samples taken along each axis of a shrunk texture's footprint
+const MAX_FOOTPRINT_SAMPLES: usize = 16;
+
+struct Texture {
+    size: [usize; 2],
+    pixels: Vec<Color32>,
+}
+
+impl Texture {
+    fn from_delta(delta: &ImageDelta) -> Self {
+        let pixels = match &delta.image {
+            ImageData::Color(image) => image.pixels.clone(),
+            ImageData::Font(image) => image.srgba_pixels(None).collect(),
+        };
+        Self { size: delta.image.size(), pixels }
+    }
+
+    fn patch(&mut self, pos: [usize; 2], patch: &Texture) {
+        for y in 0..patch.size[1] {
+            let row = (pos[1] + y) * self.size[0] + pos[0];
+            self.pixels[row..row + patch.size[0]].copy_from_slice(&patch.pixels[y * patch.size[0]..(y + 1) * patch.size[0]]);
+        }
+    }
+
+    /// Premultiplied texel at integer coordinates, clamped to the edge
+    fn texel(&self, x: isize, y: isize) -> [f32; 4] {
+        let x = x.clamp(0, self.size[0] as isize - 1) as usize;
+        let y = y.clamp(0, self.size[1] as isize - 1) as usize;
+        let c = self.pixels[y * self.size[0] + x];
+        [c.r(), c.g(), c.b(), c.a()].map(|v| v as f32 / 255.0)
+    }
+
+    /// Bilinear sample at texel-space coordinates
+    fn bilinear(&self, x: f32, y: f32) -> [f32; 4] {
+        let (x, y) = (x - 0.5, y - 0.5);
+        let (x0, y0) = (x.floor(), y.floor());
+        let (fx, fy) = (x - x0, y - y0);
+        let (x0, y0) = (x0 as isize, y0 as isize);
+        let [a, b, c, d] = [self.texel(x0, y0), self.texel(x0 + 1, y0), self.texel(x0, y0 + 1), self.texel(x0 + 1, y0 + 1)];
+        std::array::from_fn(|k| (a[k] * (1.0 - fx) + b[k] * fx) * (1.0 - fy) + (c[k] * (1.0 - fx) + d[k] * fx) * fy)
+    }
+
+    /// Average of bilinear samples over a `footprint`-texel area centered on `uv`
+    fn sample(&self, uv: Pos2, footprint: Vec2) -> [f32; 4] {
+        let center = Vec2::new(uv.x * self.size[0] as f32, uv.y * self.size[1] as f32);
+        let n = [footprint.x, footprint.y].map(|f| (f.ceil() as usize).clamp(1, MAX_FOOTPRINT_SAMPLES));
+        if n == [1, 1] {
+            return self.bilinear(center.x, center.y);
+        }
+        let mut sum = [0.0; 4];
+        for j in 0..n[1] {
+            for i in 0..n[0] {
+                let x = center.x + ((i as f32 + 0.5) / n[0] as f32 - 0.5) * footprint.x;
+                let y = center.y + ((j as f32 + 0.5) / n[1] as f32 - 0.5) * footprint.y;
+                let s = self.bilinear(x, y);
+                for k in 0..4 {
+                    sum[k] += s[k];
+                }
+            }
+        }
+        sum.map(|v| v / (n[0] * n[1]) as f32)
+    }
+}
+
+/// An egui context rendered into memory instead of a window
+pub struct Offscreen {
+    ctx: egui::Context,
+    /// Screen size in points
+    size: Vec2,
+    pixels_per_point: f32,
+    textures: HashMap<TextureId, Texture>,
+    primitives: Vec<ClippedPrimitive>,
+}
+
+impl Offscreen {
+    /// A screen `size` points across, rendered at `pixels_per_point`
+    pub fn new(size: Vec2, pixels_per_point: f32) -> Self {
+        let ctx = egui::Context::default();
+        egui_extras::install_image_loaders(&ctx);
+        Self { ctx, size, pixels_per_point, textures: HashMap::new(), primitives: Vec::new() }
+    }
+
+    pub fn context(&self) -> &egui::Context {
+        &self.ctx
+    }
+
+    /// Size of the rendered image in pixels
+    pub fn pixel_size(&self) -> [u32; 2] {
+        [(self.size.x * self.pixels_per_point).round() as u32, (self.size.y * self.pixels_per_point).round() as u32]
+    }
+
+    /// Register every file under `dir` by its site path (`dir/logos/a.png` as
+    /// `/logos/a.png`), which is what assets resolve to natively. Returns the
+    /// number of files registered.
+    pub fn load_assets(&self, dir: &Path) -> io::Result<usize> {
+        fn walk(ctx: &egui::Context, root: &Path, dir: &Path) -> io::Result<usize> {
+            let mut count = 0;
+            for entry in std::fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    count += walk(ctx, root, &path)?;
+                } else {
+                    let relative = path.strip_prefix(root).expect("walked from root");
+                    let uri = format!("/{}", relative.to_string_lossy().replace('\\', "/"));
+                    ctx.include_bytes(uri, std::fs::read(&path)?);
+                    count += 1;
+                }
+            }
+            Ok(count)
+        }
+        walk(&self.ctx, dir, dir)
+    }
+
+    /// Run one frame at `time` seconds with the given input events. Returns
+    /// true if the frame asked to be followed immediately by another.
+    pub fn run(&mut self, time: f64, events: Vec<egui::Event>, ui: impl FnMut(&egui::Context)) -> bool {
+        let mut input = egui::RawInput { screen_rect: Some(Rect::from_min_size(Pos2::ZERO, self.size)), time: Some(time), events, ..Default::default() };
+        input.viewports.entry(ViewportId::ROOT).or_default().native_pixels_per_point = Some(self.pixels_per_point);
+
+        let output = self.ctx.run(input, ui);
+        for (id, delta) in &output.textures_delta.set {
+            let texture = Texture::from_delta(delta);
+            match (delta.pos, self.textures.get_mut(id)) {
+                (Some(pos), Some(existing)) => existing.patch(pos, &texture),
+                _ => {
+                    self.textures.insert(*id, texture);
+                }
+            }
+        }
+        self.primitives = self.ctx.tessellate(output.shapes, output.pixels_per_point);
+        for id in &output.textures_delta.free {
+            self.textures.remove(id);
+        }
+        output.viewport_output.get(&ViewportId::ROOT).is_some_and(|v| v.repaint_delay.is_zero())
+    }
+
+    /// Rasterize the last frame
+    pub fn image(&self) -> image::RgbaImage {
+        self.image_in_bands(std::thread::available_parallelism().map_or(1, |n| n.get()))
+    }
+
+    /// Rasterize the last frame as `threads` bands of rows, one per thread.
+    /// Bands are independent, and each blends its triangles in the same order,
+    /// so the image doesn't depend on how many there are.
+    fn image_in_bands(&self, threads: usize) -> image::RgbaImage {
+        let [w, h] = self.pixel_size();
+        let (w, h) = (w as usize, h as usize);
+        let mut target = vec![[0.0f32; 4]; w * h];
+        let band_height = h.div_ceil(threads).max(1);
+        std::thread::scope(|scope| {
+            for (i, rows) in target.chunks_mut(band_height * w).enumerate() {
+                scope.spawn(move || self.rasterize(Band { rows, width: w, top: i * band_height }));
+            }
+        });
+        image::RgbaImage::from_fn(w as u32, h as u32, |x, y| {
+            let [r, g, b, a] = target[y as usize * w + x as usize];
+            // Back from premultiplied alpha for the file
+            let un = |c: f32| if a > 0.0 { (c / a * 255.0).round().clamp(0.0, 255.0) as u8 } else { 0 };
+            image::Rgba([un(r), un(g), un(b), (a * 255.0).round() as u8])
+        })
+    }
+
+    /// Fill one band of the image with every primitive that reaches it
+    fn rasterize(&self, mut band: Band) {
+        let bounds = band.bounds();
+        for ClippedPrimitive { clip_rect, primitive } in &self.primitives {
+            let Primitive::Mesh(mesh) = primitive else { continue };
+            let Some(texture) = self.textures.get(&mesh.texture_id) else { continue };
+            let clip = Rect::from_min_max((clip_rect.min.to_vec2() * self.pixels_per_point).to_pos2(), (clip_rect.max.to_vec2() * self.pixels_per_point).to_pos2()).intersect(bounds);
+            if !clip.is_positive() {
+                continue;
+            }
+            for tri in mesh.indices.chunks_exact(3) {
+                let vertices = [tri[0], tri[1], tri[2]].map(|i| &mesh.vertices[i as usize]);
+                fill_triangle(&mut band, clip, vertices, self.pixels_per_point, texture);
+            }
+        }
+    }
+}
+
+/// Rows of premultiplied pixels from the image, starting at row `top`
+struct Band<'a> {
+    rows: &'a mut [[f32; 4]],
+    width: usize,
+    top: usize,
+}
+
+impl Band<'_> {
+    /// The pixels covered, in image coordinates
+    fn bounds(&self) -> Rect {
+        Rect::from_min_size(Pos2::new(0.0, self.top as f32), Vec2::new(self.width as f32, (self.rows.len() / self.width) as f32))
+    }
+
+    fn pixel(&mut self, x: usize, y: usize) -> &mut [f32; 4] {
+        &mut self.rows[(y - self.top) * self.width + x]
+    }
+}
+
+/// Subpixel steps per pixel that vertex positions are snapped to, so coverage
+/// tests are exact and triangles sharing an edge neither overlap nor gap
+const SUBPIXELS: f32 = 256.0;
+
+type Fixed = [i64; 2];
+
+fn to_fixed(p: Pos2) -> Fixed {
+    [(p.x * SUBPIXELS).round() as i64, (p.y * SUBPIXELS).round() as i64]
+}
+
+/// Twice the signed area of `a b p`, positive when counter-clockwise in screen space
+fn edge(a: Fixed, b: Fixed, p: Fixed) -> i64 {
+    (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])
+}
+
+/// Whether an edge of a positively wound triangle owns pixel centers exactly
+/// on it, so shared edges aren't blended twice
+fn is_top_left(a: Fixed, b: Fixed) -> bool {
+    (a[1] == b[1] && b[0] < a[0]) || b[1] > a[1]
+}
+
+fn fill_triangle(band: &mut Band, clip: Rect, vertices: [&Vertex; 3], pixels_per_point: f32, texture: &Texture) {
+    let mut v = vertices;
+    let mut p = v.map(|v| to_fixed((v.pos.to_vec2() * pixels_per_point).to_pos2()));
+    let mut area = edge(p[0], p[1], p[2]);
+    if area == 0 {
+        return;
+    }
+    if area < 0 {
+        v.swap(1, 2);
+        p.swap(1, 2);
+        area = -area;
+    }
+    let bounds = Rect::from_points(&p.map(|p| Pos2::new(p[0] as f32, p[1] as f32) / SUBPIXELS)).intersect(clip);
+    if !bounds.is_positive() {
+        return;
+    }
+
+    // Barycentric edge values at a point, and the weights they make
+    let edges = |q: Fixed| [edge(p[1], p[2], q), edge(p[2], p[0], q), edge(p[0], p[1], q)];
+    let weights = |e: [i64; 3]| e.map(|e| (e as f64 / area as f64) as f32);
+    let uv_at = |w: [f32; 3]| Pos2::new(w[0] * v[0].uv.x + w[1] * v[1].uv.x + w[2] * v[2].uv.x, w[0] * v[0].uv.y + w[1] * v[1].uv.y + w[2] * v[2].uv.y);
+    // Texels per pixel, constant across the triangle since it's affine
+    let step = SUBPIXELS as i64;
+    let origin = uv_at(weights(edges([0, 0])));
+    let (dx, dy) = (uv_at(weights(edges([step, 0]))) - origin, uv_at(weights(edges([0, step]))) - origin);
+    let footprint = Vec2::new(dx.x.abs().max(dy.x.abs()) * texture.size[0] as f32, dx.y.abs().max(dy.y.abs()) * texture.size[1] as f32);
+    let owns = [is_top_left(p[1], p[2]), is_top_left(p[2], p[0]), is_top_left(p[0], p[1])];
+    let colors = v.map(|v| [v.color.r(), v.color.g(), v.color.b(), v.color.a()].map(|c| c as f32 / 255.0));
+
+    for y in bounds.min.y.floor() as usize..bounds.max.y.ceil() as usize {
+        for x in bounds.min.x.floor() as usize..bounds.max.x.ceil() as usize {
+            if !clip.contains(Pos2::new(x as f32 + 0.5, y as f32 + 0.5)) {
+                continue;
+            }
+            let e = edges([x as i64 * step + step / 2, y as i64 * step + step / 2]);
+            if (0..3).any(|i| e[i] < 0 || (e[i] == 0 && !owns[i])) {
+                continue;
+            }
+            let w = weights(e);
+            let texel = texture.sample(uv_at(w), footprint);
+            let dst = band.pixel(x, y);
+            let alpha = (0..3).map(|i| w[i] * colors[i][3]).sum::<f32>() * texel[3];
+            for k in 0..4 {
+                let src = if k == 3 { alpha } else { (0..3).map(|i| w[i] * colors[i][k]).sum::<f32>() * texel[k] };
+                dst[k] = src + dst[k] * (1.0 - alpha);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_and_blends_rects() {
+        let mut offscreen = Offscreen::new(Vec2::new(20.0, 10.0), 2.0);
+        offscreen.run(0.0, Vec::new(), |ctx| {
+            let painter = ctx.layer_painter(egui::LayerId::background());
+            painter.rect_filled(Rect::from_min_size(Pos2::ZERO, Vec2::new(20.0, 10.0)), 0.0, Color32::from_rgb(0, 0, 255));
+            painter.rect_filled(Rect::from_min_size(Pos2::ZERO, Vec2::new(10.0, 10.0)), 0.0, Color32::from_rgba_premultiplied(128, 0, 0, 128));
+        });
+        let image = offscreen.image();
+        assert_eq!(image.dimensions(), (40, 20));
+        assert_eq!(image.get_pixel(30, 10).0, [0, 0, 255, 255]);
+        let [r, g, b, a] = image.get_pixel(5, 10).0;
+        assert!(r.abs_diff(128) <= 1 && g == 0 && b.abs_diff(127) <= 1 && a == 255, "{:?}", [r, g, b, a]);
+    }
+
+    #[test]
+    fn shared_edges_are_blended_once() {
+        let mut offscreen = Offscreen::new(Vec2::new(8.0, 8.0), 1.0);
+        offscreen.run(0.0, Vec::new(), |ctx| {
+            let mut mesh = egui::Mesh::default();
+            mesh.add_colored_rect(Rect::from_min_size(Pos2::ZERO, Vec2::splat(8.0)), Color32::from_rgba_unmultiplied(255, 255, 255, 128));
+            ctx.layer_painter(egui::LayerId::background()).add(mesh);
+        });
+        // Every pixel is covered exactly once, including along the diagonal
+        let image = offscreen.image();
+        assert!(image.pixels().all(|p| p.0[3] == 128), "{:?}", image.pixels().map(|p| p.0[3]).collect::<Vec<_>>());
+    }
+
+    /// A short scripted run of the page: the intro animating, then a hover
+    fn render_page() -> Offscreen {
+        let mut offscreen = Offscreen::new(Vec2::new(480.0, 360.0), 1.0);
+        let mut app = crate::AlexandriaApp::default();
+        for (n, pointer) in [None, None, Some(Pos2::new(240.0, 300.0)), Some(Pos2::new(240.0, 300.0))].into_iter().enumerate() {
+            let time = n as f64 / 10.0;
+            let event = pointer.map_or(egui::Event::PointerGone, egui::Event::PointerMoved);
+            app.set_time(time);
+            offscreen.run(time, vec![event], |ctx| app.ui(ctx));
+        }
+        offscreen
+    }
+
+    #[test]
+    fn frames_are_deterministic() {
+        let first = render_page();
+        let image = first.image_in_bands(1);
+        assert!(image.pixels().any(|p| p.0 != image.get_pixel(0, 0).0), "the page rendered blank");
+        for threads in [2, 3, 8] {
+            assert!(first.image_in_bands(threads) == image, "{threads} bands differ from one");
+        }
+        assert!(render_page().image_in_bands(4) == image, "a second run differs from the first");
+    }
+
+    #[test]
+    fn shrunk_textures_are_averaged() {
+        let texture = Texture { size: [4, 1], pixels: vec![Color32::WHITE, Color32::BLACK, Color32::WHITE, Color32::BLACK] };
+        let sample = texture.sample(Pos2::new(0.5, 0.5), Vec2::new(4.0, 1.0));
+        assert!((sample[0] - 0.5).abs() < 0.01);
+        assert_eq!(texture.sample(Pos2::new(0.125, 0.5), Vec2::splat(1.0)), [1.0; 4]);
+    }
+}
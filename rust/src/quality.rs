@@ -0,0 +1,239 @@
+//! Adaptive rendering quality
+//!
+//! The hover effects are the expensive part of a frame: layered glow strokes,
+//! scanline glow lines and pulsing tags. Most of their cost is tessellation and
+//! GPU fill, after `ui` returns, so [`QualityGovernor`] watches a rolling
+//! window of the interval between frames while the page is animating. It drops
+//! one [`Quality`] level at a time when frames come slower than about 40 fps,
+//! then climbs back once they arrive at close to 60 fps again. Only
+//! continuously repainted frames are sampled, since an idle page's frames are
+//! spaced by its repaint schedule, and gaps long enough to be a hitch or a
+//! background tab are skipped. A display that refreshes at 30 Hz reads as slow
+//! and runs without the effects, which costs nothing but the effects.
+//!
+//! A level whose recovery is followed straight away by a drop is on the edge
+//! of the budget, so each such flip doubles the wait before the next change,
+//! rather than switching effects on and off every few seconds.
+//!
+//! There is no level that lowers the render resolution: eframe sizes the
+//! canvas from the device pixel ratio, and egui's zoom factor would shrink the
+//! layout rather than the backing store.
+
+use std::collections::VecDeque;
+
+/// Rendering quality, from everything on to the cheapest fallback. Each level
+/// keeps the savings of the ones before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Quality {
+    Full,
+    /// One glow stroke instead of three around cards and footer icons
+    SingleGlow,
+    /// Scanline without its six glow lines
+    PlainScanline,
+    /// Hovered tags glow steadily instead of pulsing
+    StaticTags,
+}
+
+/// Frames averaged before a decision
+const WINDOW: usize = 30;
+
+/// Average interval between frames above which quality steps down
+const SLOW_FRAME: f64 = 1.0 / 40.0;
+
+/// Average interval between frames below which quality steps back up
+const FAST_FRAME: f64 = 1.0 / 55.0;
+
+/// Intervals longer than this are a stall or a throttled tab, not a frame
+const MAX_INTERVAL: f64 = 0.25;
+
+/// Seconds to wait after a change before making another
+const COOLDOWN: f64 = 3.0;
+
+/// Longest the cooldown grows to when the level keeps flipping
+const MAX_COOLDOWN: f64 = 120.0;
+
+impl Quality {
+    const LEVELS: [Quality; 4] = [Quality::Full, Quality::SingleGlow, Quality::PlainScanline, Quality::StaticTags];
+
+    pub fn layered_glows(self) -> bool {
+        self < Quality::SingleGlow
+    }
+
+    pub fn scanline_glow(self) -> bool {
+        self < Quality::PlainScanline
+    }
+
+    pub fn tag_pulse(self) -> bool {
+        self < Quality::StaticTags
+    }
+
+    fn step(self, by: isize) -> Self {
+        let idx = Self::LEVELS.iter().position(|&q| q == self).unwrap_or(0) as isize + by;
+        Self::LEVELS[idx.clamp(0, Self::LEVELS.len() as isize - 1) as usize]
+    }
+}
+
+pub(crate) struct QualityGovernor {
+    level: Quality,
+    samples: VecDeque<f64>,
+    last_change: f64,
+    /// Whether the last change was a recovery
+    recovered: bool,
+    cooldown: f64,
+}
+
+impl Default for QualityGovernor {
+    fn default() -> Self {
+        Self {
+            level: Quality::Full,
+            samples: VecDeque::with_capacity(WINDOW),
+            last_change: f64::NEG_INFINITY,
+            recovered: false,
+            cooldown: COOLDOWN,
+        }
+    }
+}
+
+impl QualityGovernor {
+    pub fn level(&self) -> Quality {
+        self.level
+    }
+
+    /// Record the interval since the previous frame for a continuously
+    /// repainted frame at clock time `now`. Returns whether the level changed.
+    pub fn record(&mut self, now: f64, interval: f64) -> bool {
+        if interval > MAX_INTERVAL {
+            return false;
+        }
+        if self.samples.len() == WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(interval);
+        if self.samples.len() < WINDOW || now - self.last_change < self.cooldown {
+            return false;
+        }
+
+        let average = self.samples.iter().sum::<f64>() / WINDOW as f64;
+        let next = if average > SLOW_FRAME {
+            self.level.step(1)
+        } else if average < FAST_FRAME {
+            self.level.step(-1)
+        } else {
+            self.level
+        };
+        if next == self.level {
+            return false;
+        }
+        let recovering = next < self.level;
+        if !recovering && self.recovered {
+            self.cooldown = (self.cooldown * 2.0).min(MAX_COOLDOWN);
+        }
+        log::info!("render quality {:?} -> {:?} (average frame interval {:.1} ms)", self.level, next, average * 1000.0);
+        self.recovered = recovering;
+        self.level = next;
+        self.last_change = now;
+        // Judge the new level on its own frames
+        self.samples.clear();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feed `frames` frames `interval` seconds apart starting at `start`,
+    /// returning the end time
+    fn run(governor: &mut QualityGovernor, start: f64, frames: usize, interval: f64) -> f64 {
+        let mut t = start;
+        for _ in 0..frames {
+            t += interval;
+            governor.record(t, interval);
+        }
+        t
+    }
+
+    #[test]
+    fn stays_at_full_quality_at_60_fps() {
+        let mut g = QualityGovernor::default();
+        let t = run(&mut g, 0.0, 600, 1.0 / 60.0);
+        assert_eq!(g.level(), Quality::Full);
+        run(&mut g, t, 600, 1.0 / 120.0);
+        assert_eq!(g.level(), Quality::Full);
+    }
+
+    #[test]
+    fn steps_down_one_level_at_a_time() {
+        let mut g = QualityGovernor::default();
+        let t = run(&mut g, 0.0, WINDOW, 0.05);
+        assert_eq!(g.level(), Quality::SingleGlow);
+        // Still slow, but within the cooldown
+        let t = run(&mut g, t, WINDOW, 0.05);
+        assert_eq!(g.level(), Quality::SingleGlow);
+        run(&mut g, t, 1000, 0.05);
+        assert_eq!(g.level(), Quality::StaticTags);
+    }
+
+    #[test]
+    fn sustained_slow_frames_step_down_and_fast_ones_recover() {
+        // A GPU-bound phone: 25 fps however little `ui` itself takes
+        let mut g = QualityGovernor::default();
+        let t = run(&mut g, 0.0, 1000, 0.04);
+        assert_eq!(g.level(), Quality::StaticTags);
+        run(&mut g, t, 2000, 1.0 / 60.0);
+        assert_eq!(g.level(), Quality::Full);
+    }
+
+    #[test]
+    fn holds_in_the_dead_band() {
+        let mut g = QualityGovernor::default();
+        let t = run(&mut g, 0.0, WINDOW, 0.05);
+        assert_eq!(g.level(), Quality::SingleGlow);
+        // 50 fps is neither slow enough to drop nor fast enough to recover
+        run(&mut g, t, 1000, 1.0 / 50.0);
+        assert_eq!(g.level(), Quality::SingleGlow);
+    }
+
+    #[test]
+    fn stalls_are_not_frames() {
+        // 60 fps with a long gap every 20 frames, as after a hitch or a tab switch
+        let mut g = QualityGovernor::default();
+        let mut t = 0.0;
+        for n in 0..600 {
+            let interval = if n % 20 == 0 { 2.0 } else { 1.0 / 60.0 };
+            t += interval;
+            g.record(t, interval);
+        }
+        assert_eq!(g.level(), Quality::Full);
+    }
+
+    #[test]
+    fn flipping_between_levels_backs_off() {
+        // Fast only with static tags, so every recovery is followed by a drop
+        let mut g = QualityGovernor::default();
+        let mut changes = Vec::new();
+        let mut t = 0.0;
+        while t < 600.0 {
+            let interval = if g.level() >= Quality::StaticTags { 1.0 / 60.0 } else { 1.0 / 30.0 };
+            t += interval;
+            if g.record(t, interval) {
+                changes.push(t);
+            }
+        }
+        let gaps: Vec<f64> = changes.windows(2).map(|w| w[1] - w[0]).collect();
+        // Down to StaticTags, then each flip waits at least as long as the last
+        // (give or take a frame)
+        assert!(gaps[2..].windows(2).all(|w| w[1] > w[0] - 0.1), "{gaps:?}");
+        assert!(gaps.last().is_some_and(|&gap| gap >= MAX_COOLDOWN), "{gaps:?}");
+        assert!(changes.len() < 20, "{} changes in 10 minutes", changes.len());
+    }
+
+    #[test]
+    fn levels_accumulate_savings() {
+        assert!(Quality::Full.layered_glows() && Quality::Full.scanline_glow() && Quality::Full.tag_pulse());
+        assert!(!Quality::PlainScanline.layered_glows() && !Quality::PlainScanline.scanline_glow());
+        assert!(Quality::PlainScanline.tag_pulse());
+        let lowest = Quality::StaticTags;
+        assert!(!lowest.layered_glows() && !lowest.scanline_glow() && !lowest.tag_pulse());
+    }
+}
@@ -0,0 +1,119 @@
+//! Idle-aware repaint scheduling
+//!
+//! Instead of repainting every frame, each animation reports when it next needs
+//! a frame: the typewriter at its next keystroke, the cursor at its next blink,
+//! hover tweens and glow pulses straight away while they're moving. The app
+//! asks egui for a single repaint at the earliest of those times, so a page
+//! nobody is interacting with wakes a few times a second instead of sixty.
+
+use std::time::Duration;
+
+/// Earliest wake-up requested by any animation during one frame
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RepaintSchedule {
+    now: f64,
+    next: f64,
+}
+
+impl RepaintSchedule {
+    /// Start collecting wake-ups for a frame at `now`
+    pub fn new(now: f64) -> Self {
+        Self { now, next: f64::INFINITY }
+    }
+
+    /// Request a frame at `time`, on the same clock as `now`
+    pub fn at(&mut self, time: f64) {
+        self.next = self.next.min(time);
+    }
+
+    /// Request the next frame as soon as possible, for continuous motion
+    pub fn asap(&mut self) {
+        self.at(self.now);
+    }
+
+    /// Whether something asked for the very next frame
+    pub fn is_continuous(&self) -> bool {
+        self.next <= self.now
+    }
+
+    /// Time until the requested frame, or `None` if nothing is animating
+    pub fn delay(&self) -> Option<Duration> {
+        self.next.is_finite().then(|| Duration::from_secs_f64((self.next - self.now).max(0.0)))
+    }
+
+    pub fn apply(&self, ctx: &egui::Context) {
+        if let Some(delay) = self.delay() {
+            ctx.request_repaint_after(delay);
+        }
+    }
+}
+
+/// Start of the next `interval`-long tick after `time`, for periodic effects
+/// like a blinking cursor
+pub(crate) fn next_tick(time: f64, interval: f64) -> f64 {
+    ((time / interval).floor() + 1.0) * interval
+}
+
+/// Ease `value` toward `target` at `speed` per second, snapping once it's
+/// within a visually identical distance. Returns whether it's still moving.
+pub(crate) fn approach(value: &mut f32, target: f32, speed: f32, dt: f32) -> bool {
+    *value += (target - *value) * speed * dt;
+    *value = value.clamp(0.0, 1.0);
+    if (target - *value).abs() < 1e-3 {
+        *value = target;
+    }
+    *value != target
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nothing_requested_means_no_repaint() {
+        assert_eq!(RepaintSchedule::new(5.0).delay(), None);
+    }
+
+    #[test]
+    fn earliest_wakeup_wins() {
+        let mut s = RepaintSchedule::new(10.0);
+        s.at(12.0);
+        s.at(10.5);
+        s.at(11.0);
+        assert_eq!(s.delay(), Some(Duration::from_secs_f64(0.5)));
+        assert!(!s.is_continuous());
+    }
+
+    #[test]
+    fn asap_and_overdue_wakeups_are_immediate() {
+        let mut s = RepaintSchedule::new(3.0);
+        s.at(2.0);
+        assert_eq!(s.delay(), Some(Duration::ZERO));
+        let mut s = RepaintSchedule::new(3.0);
+        s.at(4.0);
+        s.asap();
+        assert_eq!(s.delay(), Some(Duration::ZERO));
+        assert!(s.is_continuous());
+    }
+
+    #[test]
+    fn ticks() {
+        assert_eq!(next_tick(0.0, 0.5), 0.5);
+        assert_eq!(next_tick(0.2, 0.5), 0.5);
+        assert_eq!(next_tick(0.5, 0.5), 1.0);
+        assert_eq!(next_tick(7.9, 2.0), 8.0);
+    }
+
+    #[test]
+    fn tweens_settle() {
+        let mut v = 0.0;
+        let mut frames = 0;
+        while approach(&mut v, 1.0, 8.0, 1.0 / 60.0) {
+            frames += 1;
+            assert!(frames < 120, "tween never settled");
+        }
+        assert_eq!(v, 1.0);
+        assert!(!approach(&mut v, 1.0, 8.0, 1.0 / 60.0));
+        assert!(approach(&mut v, 0.0, 8.0, 1.0 / 60.0));
+    }
+}
@@ -0,0 +1,237 @@
+//! Scripted pointer input for rendering frame sequences
+//!
+//! A script lists what the pointer does over time, one action per line:
+//!
+//! ```text
+//! # Comments and blank lines are ignored
+//! hover product 3 from 2s to 5s
+//! hover product CycleScan from 5s to 7s
+//! hover metric 2 from 1s to 1500ms
+//! hover footer Twitter from 7s to 8s
+//! pointer 640,360 from 0s to 1s
+//! scroll 600 from 8s to 9s
+//! scroll -200 at 10s
+//! ```
+//!
+//! Products, metrics and footer links are numbered from 1 in page order, or
+//! named by their name, label or title. `pointer` holds the pointer at a
+//! position in points, and `scroll` scrolls the page down (or up, if negative)
+//! by that many points spread evenly over the range. Ranges include their
+//! start but not their end. Where hovers overlap, the one that started last
+//! wins. Times are seconds on the simulated clock, with an optional `s` or `ms`
+//! unit.
+
+use std::fmt;
+
+use egui::{Pos2, Rect, Vec2};
+
+use crate::{Target, FOOTER_LINKS, METRICS, PRODUCTS};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptError {
+    /// 1-based line number
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Action {
+    Hover(Target),
+    Pointer(Pos2),
+    /// Points to scroll down by
+    Scroll(f32),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Step {
+    action: Action,
+    start: f64,
+    end: f64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Script {
+    steps: Vec<Step>,
+}
+
+/// Seconds from `2`, `2.5s` or `500ms`
+fn parse_time(token: &str) -> Result<f64, String> {
+    let (number, scale) = match token.strip_suffix("ms") {
+        Some(number) => (number, 0.001),
+        None => (token.strip_suffix('s').unwrap_or(token), 1.0),
+    };
+    match number.parse::<f64>() {
+        Ok(value) if value.is_finite() && value >= 0.0 => Ok(value * scale),
+        _ => Err(format!("expected a time like 2s or 500ms, found {token:?}")),
+    }
+}
+
+/// An element by 1-based number or by (case-insensitive) name
+fn parse_target(kind: &str, name: &str) -> Result<Target, String> {
+    let (names, make): (Vec<&str>, fn(usize) -> Target) = match kind {
+        "product" => (PRODUCTS.iter().map(|p| p.name).collect(), Target::Product),
+        "metric" => (METRICS.iter().map(|m| m.label).collect(), Target::Metric),
+        "footer" => (FOOTER_LINKS.iter().map(|l| l.title).collect(), Target::Footer),
+        other => return Err(format!("can't hover {other:?}, only a product, metric or footer link")),
+    };
+    let idx = match name.parse::<usize>() {
+        Ok(n) if (1..=names.len()).contains(&n) => n - 1,
+        Ok(n) => return Err(format!("there is no {kind} {n}, they're numbered 1 to {}", names.len())),
+        Err(_) => names.iter().position(|n| n.eq_ignore_ascii_case(name)).ok_or_else(|| format!("no {kind} is called {name:?}"))?,
+    };
+    Ok(make(idx))
+}
+
+fn parse_line(line: &str) -> Result<Step, String> {
+    let words: Vec<_> = line.split_whitespace().collect();
+    // Every action ends in `from A to B` or `at T`
+    let (args, start, end) = match words.as_slice() {
+        [args @ .., "from", a, "to", b] => (args, parse_time(a)?, parse_time(b)?),
+        [args @ .., "at", t] => {
+            let t = parse_time(t)?;
+            (args, t, t)
+        }
+        _ => return Err("expected the action to end in `from <time> to <time>` or `at <time>`".into()),
+    };
+    if end < start {
+        return Err(format!("range ends at {end}s, before it starts at {start}s"));
+    }
+    let action = match args {
+        ["hover", kind, name @ ..] if !name.is_empty() => Action::Hover(parse_target(kind, &name.join(" "))?),
+        ["pointer", position] => {
+            let coords = position.split_once(',').and_then(|(x, y)| Some(Pos2::new(x.parse().ok()?, y.parse().ok()?)));
+            Action::Pointer(coords.ok_or_else(|| format!("expected a position like 640,360, found {position:?}"))?)
+        }
+        ["scroll", amount] => Action::Scroll(amount.parse().map_err(|_| format!("expected points to scroll, found {amount:?}"))?),
+        _ => return Err(format!("unknown action {:?}", args.join(" "))),
+    };
+    if matches!(action, Action::Hover(_) | Action::Pointer(_)) && start == end {
+        return Err("the pointer needs a range to stay for, `from <time> to <time>`".into());
+    }
+    Ok(Step { action, start, end })
+}
+
+/// Where the pointer goes to hover `target`. Cards are hovered in their
+/// bottom padding, clear of the glossary terms and links in their copy.
+fn hover_point(target: Target, rect: Rect) -> Pos2 {
+    match target {
+        Target::Product(_) => Pos2::new(rect.center().x, rect.bottom() - 8.0),
+        Target::Metric(_) | Target::Footer(_) => rect.center(),
+    }
+}
+
+impl Script {
+    pub fn parse(source: &str) -> Result<Self, ScriptError> {
+        let mut steps = Vec::new();
+        for (idx, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            steps.push(parse_line(line).map_err(|message| ScriptError { line: idx + 1, message })?);
+        }
+        Ok(Self { steps })
+    }
+
+    /// When the last action ends, in seconds
+    pub fn end(&self) -> f64 {
+        self.steps.iter().map(|s| s.end).fold(0.0, f64::max)
+    }
+
+    /// Where the pointer is at `time`, given where each element was laid out,
+    /// or `None` if no action holds it (or its target isn't on the page)
+    pub fn pointer(&self, time: f64, hit_area: impl Fn(Target) -> Option<Rect>) -> Option<Pos2> {
+        self.steps
+            .iter()
+            .filter(|s| s.start <= time && time < s.end)
+            .filter(|s| !matches!(s.action, Action::Scroll(_)))
+            .max_by(|a, b| a.start.total_cmp(&b.start))
+            .and_then(|s| match s.action {
+                Action::Hover(target) => hit_area(target).map(|rect| hover_point(target, rect)),
+                Action::Pointer(pos) => Some(pos),
+                Action::Scroll(_) => None,
+            })
+    }
+
+    /// Points scrolled down between `from` (excluded) and `to` (included)
+    pub fn scroll(&self, from: f64, to: f64) -> Vec2 {
+        let down: f32 = self
+            .steps
+            .iter()
+            .filter_map(|s| match s.action {
+                Action::Scroll(amount) if s.end == s.start => (from < s.start && s.start <= to).then_some(amount),
+                Action::Scroll(amount) => {
+                    let overlap = (to.min(s.end) - from.max(s.start)).max(0.0);
+                    Some(amount * (overlap / (s.end - s.start)) as f32)
+                }
+                _ => None,
+            })
+            .sum();
+        Vec2::new(0.0, down)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(source: &str) -> String {
+        Script::parse(source).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn times_and_targets() {
+        assert_eq!(parse_time("2"), Ok(2.0));
+        assert_eq!(parse_time("2.5s"), Ok(2.5));
+        assert_eq!(parse_time("500ms"), Ok(0.5));
+        assert!(parse_time("-1s").is_err());
+        assert_eq!(parse_target("product", "3"), Ok(Target::Product(2)));
+        assert_eq!(parse_target("product", "caffeine launcher"), Ok(Target::Product(PRODUCTS.len() - 1)));
+        assert_eq!(parse_target("footer", "Twitter"), Ok(Target::Footer(0)));
+        assert!(parse_target("product", "0").is_err());
+        assert!(parse_target("tag", "1").is_err());
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        assert_eq!(error("# intro\n\nhover product 99 from 1s to 2s"), format!("line 3: there is no product 99, they're numbered 1 to {}", PRODUCTS.len()));
+        assert!(error("hover product 3 from 5s to 2s").starts_with("line 1: range ends"));
+        assert!(error("hover product 3").contains("from <time> to <time>"));
+        assert!(error("wiggle 3 at 1s").contains("unknown action"));
+        assert!(error("hover product 3 at 1s").contains("needs a range"));
+    }
+
+    #[test]
+    fn latest_hover_holds_the_pointer() {
+        let script = Script::parse("hover product 1 from 1s to 4s\npointer 5,5 from 2s to 3s # on top\nhover metric 1 from 3s to 3.5s").unwrap();
+        let area = |target| match target {
+            Target::Product(0) => Some(Rect::from_min_size(Pos2::new(0.0, 100.0), Vec2::splat(100.0))),
+            Target::Metric(0) => Some(Rect::from_min_size(Pos2::ZERO, Vec2::splat(10.0))),
+            _ => None,
+        };
+        assert_eq!(script.pointer(0.5, area), None);
+        assert_eq!(script.pointer(1.0, area), Some(Pos2::new(50.0, 192.0)));
+        assert_eq!(script.pointer(2.5, area), Some(Pos2::new(5.0, 5.0)));
+        assert_eq!(script.pointer(3.2, area), Some(Pos2::new(5.0, 5.0)));
+        assert_eq!(script.pointer(3.7, area), Some(Pos2::new(50.0, 192.0)));
+        assert_eq!(script.pointer(4.0, area), None);
+        assert_eq!(script.end(), 4.0);
+    }
+
+    #[test]
+    fn scrolling_is_spread_over_its_range() {
+        let script = Script::parse("scroll 600 from 1s to 3s\nscroll -50 at 2s").unwrap();
+        assert_eq!(script.scroll(0.0, 1.0), Vec2::ZERO);
+        assert_eq!(script.scroll(1.0, 1.5), Vec2::new(0.0, 150.0));
+        assert_eq!(script.scroll(1.5, 2.0), Vec2::new(0.0, 100.0));
+        assert_eq!(script.scroll(0.0, 10.0), Vec2::new(0.0, 550.0));
+    }
+}
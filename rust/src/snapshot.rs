@@ -0,0 +1,268 @@
+//! Static HTML snapshot of the page for crawlers and link unfurlers
+//!
+//! Search engines and link previews don't run WebGL, so to them the page is an
+//! empty `<canvas>`. A [`Snapshot`] writes the same content as the
+//! [`crate::document`] fallback as plain HTML, plus the head tags they do read:
+//! a meta description, Open Graph and Twitter card tags, and JSON-LD describing
+//! Alexandria as an `Organization` and each product as a `SoftwareApplication`.
+//! The `snapshot` binary prints it for pasting into `index.html`, with the body
+//! inside `<noscript>` or behind the canvas.
+
+use std::fmt::Write;
+
+use crate::assets::AssetResolver;
+use crate::document::{self, Element};
+use crate::{ColorPalette, FooterGroup, FOOTER_LINKS, PRODUCTS, SUBTITLE_TEXT};
+
+/// Where the landing page is deployed
+pub const DEFAULT_SITE_URL: &str = "https://z6d57-uyaaa-aaaau-ac24a-cai.icp0.io/";
+
+/// Logo used for the organization
+const ORGANIZATION_LOGO: &str = "/logos/projects/high-res/alex.png";
+
+/// Id of the snapshot's root element
+const SNAPSHOT_ID: &str = "snapshot";
+
+/// Minimal JSON value, written by hand for the JSON-LD block
+enum Json {
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    fn str(s: impl Into<String>) -> Self {
+        Json::Str(s.into())
+    }
+
+    fn write(&self, out: &mut String) {
+        match self {
+            Json::Str(s) => {
+                out.push('"');
+                let mut prev = '\0';
+                for c in s.chars() {
+                    match c {
+                        '"' => out.push_str("\\\""),
+                        '\\' => out.push_str("\\\\"),
+                        '\n' => out.push_str("\\n"),
+                        '\r' => out.push_str("\\r"),
+                        '\t' => out.push_str("\\t"),
+                        // `</script>` would end the block early
+                        '/' if prev == '<' => out.push_str("\\/"),
+                        c if (c as u32) < 0x20 => {
+                            let _ = write!(out, "\\u{:04x}", c as u32);
+                        }
+                        c => out.push(c),
+                    }
+                    prev = c;
+                }
+                out.push('"');
+            }
+            Json::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    item.write(out);
+                }
+                out.push(']');
+            }
+            Json::Object(fields) => {
+                out.push('{');
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    Json::str(*name).write(out);
+                    out.push(':');
+                    value.write(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+}
+
+/// Settings for a snapshot of the page
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    /// Canonical URL of the page, used for `og:url` and the JSON-LD ids
+    pub site_url: String,
+    /// Asset base as in `crate::ASSET_BASE`, resolved against `site_url`
+    pub asset_base: Option<String>,
+    /// Path or URL of the social card image, if there is one
+    pub og_image: Option<String>,
+    /// Keep the body underneath the canvas instead of in normal flow, for
+    /// embedding outside `<noscript>`
+    pub behind_canvas: bool,
+}
+
+impl Default for Snapshot {
+    fn default() -> Self {
+        Self { site_url: DEFAULT_SITE_URL.to_owned(), asset_base: None, og_image: None, behind_canvas: false }
+    }
+}
+
+impl Snapshot {
+    fn assets(&self) -> AssetResolver {
+        AssetResolver::new(&self.site_url, self.asset_base.as_deref())
+    }
+
+    /// Page title, as in `index.html`
+    pub fn title(&self) -> String {
+        format!("Alexandria - {SUBTITLE_TEXT}")
+    }
+
+    /// Meta description: the subtitle and every product by name
+    pub fn description(&self) -> String {
+        let names: Vec<_> = PRODUCTS.iter().map(|p| p.name).collect();
+        let (last, rest) = names.split_last().expect("there are products");
+        format!("{SUBTITLE_TEXT}: {} and {last}, open source and fair launched on the Internet Computer.", rest.join(", "))
+    }
+
+    /// Tags for `<head>`: description, canonical link, Open Graph, Twitter card
+    /// and the JSON-LD block
+    pub fn head(&self) -> String {
+        let title = self.title();
+        let description = self.description();
+        let image = self.og_image.as_deref().map(|path| self.assets().resolve(path));
+        let meta = |attr: &'static str, key: &str, content: &str| Element::new("meta").attr(attr, key.to_owned()).attr("content", content.to_owned());
+
+        let mut tags = vec![
+            meta("name", "description", &description),
+            Element::new("link").attr("rel", "canonical").attr("href", self.site_url.clone()),
+            meta("property", "og:type", "website"),
+            meta("property", "og:site_name", "Alexandria"),
+            meta("property", "og:title", &title),
+            meta("property", "og:description", &description),
+            meta("property", "og:url", &self.site_url),
+        ];
+        tags.extend(image.iter().map(|url| meta("property", "og:image", url)));
+        tags.push(meta("name", "twitter:card", if image.is_some() { "summary_large_image" } else { "summary" }));
+        tags.extend(twitter_handle().map(|handle| meta("name", "twitter:site", &handle)));
+        tags.push(meta("name", "twitter:title", &title));
+        tags.push(meta("name", "twitter:description", &description));
+        tags.extend(image.iter().map(|url| meta("name", "twitter:image", url)));
+        tags.push(Element::new("script").attr("type", "application/ld+json").text(self.json_ld()));
+
+        tags.iter().map(Element::to_html).collect::<Vec<_>>().join("\n")
+    }
+
+    /// JSON-LD graph of the organization and its products
+    fn json_ld(&self) -> String {
+        let organization_id = format!("{}#organization", self.site_url);
+        let same_as = FOOTER_LINKS.iter().filter(|l| l.group == FooterGroup::Community).map(|l| Json::str(l.url)).collect();
+        let organization = Json::Object(vec![
+            ("@type", Json::str("Organization")),
+            ("@id", Json::str(organization_id.clone())),
+            ("name", Json::str("Alexandria")),
+            ("url", Json::str(self.site_url.clone())),
+            ("logo", Json::str(self.assets().resolve(ORGANIZATION_LOGO))),
+            ("description", Json::str(SUBTITLE_TEXT)),
+            ("sameAs", Json::Array(same_as)),
+        ]);
+        let applications = PRODUCTS.iter().map(|p| {
+            Json::Object(vec![
+                ("@type", Json::str("SoftwareApplication")),
+                ("name", Json::str(p.name)),
+                ("url", Json::str(p.primary_url())),
+                ("description", Json::str(document::plain(p.tagline))),
+                ("applicationCategory", Json::str("WebApplication")),
+                ("operatingSystem", Json::str("Web")),
+                ("keywords", Json::str(p.tags.join(", "))),
+                ("publisher", Json::Object(vec![("@id", Json::str(organization_id.clone()))])),
+            ])
+        });
+        let graph = Json::Object(vec![
+            ("@context", Json::str("https://schema.org")),
+            ("@graph", Json::Array(std::iter::once(organization).chain(applications).collect())),
+        ]);
+        let mut out = String::new();
+        graph.write(&mut out);
+        out
+    }
+
+    /// The page content as semantic HTML
+    pub fn body(&self) -> String {
+        let page = document::page(&ColorPalette::default(), &self.assets(), None).attr("id", SNAPSHOT_ID);
+        let page = if self.behind_canvas { page.attr("style", "z-index:-1") } else { page };
+        page.to_html()
+    }
+
+    /// A complete standalone document
+    pub fn document(&self) -> String {
+        let title = Element::new("title").text(self.title()).to_html();
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"UTF-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\">\n{title}\n{}\n</head>\n<body>\n{}\n</body>\n</html>\n",
+            self.head(),
+            self.body()
+        )
+    }
+}
+
+/// `@handle` from the community X link
+fn twitter_handle() -> Option<String> {
+    FOOTER_LINKS.iter().find_map(|l| l.url.strip_prefix("https://x.com/")).map(|handle| format!("@{handle}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn json(value: &Json) -> String {
+        let mut out = String::new();
+        value.write(&mut out);
+        out
+    }
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(json(&Json::str("a \"b\" \\ c\nd")), r#""a \"b\" \\ c\nd""#);
+        assert_eq!(json(&Json::str("</script>")), r#""<\/script>""#);
+        assert_eq!(json(&Json::Object(vec![("k", Json::Array(vec![Json::str("x"), Json::str("y")]))])), r#"{"k":["x","y"]}"#);
+    }
+
+    #[test]
+    fn head_has_description_and_social_tags() {
+        let head = Snapshot::default().head();
+        assert!(head.contains(r#"<meta name="description" content="Building ICP&#39;s Great City: lbry.app, "#));
+        assert!(head.contains(&format!(r#"<meta property="og:url" content="{DEFAULT_SITE_URL}">"#)));
+        assert!(head.contains(r#"<meta name="twitter:site" content="@alexandria_lbry">"#));
+        assert!(head.contains(r#"<meta name="twitter:card" content="summary">"#));
+        assert!(!head.contains("og:image"));
+
+        let snapshot = Snapshot { og_image: Some("/og/home.png".into()), ..Snapshot::default() };
+        let head = snapshot.head();
+        assert!(head.contains(&format!(r#"<meta property="og:image" content="{DEFAULT_SITE_URL}og/home.png">"#)));
+        assert!(head.contains(r#"<meta name="twitter:card" content="summary_large_image">"#));
+    }
+
+    #[test]
+    fn json_ld_lists_every_product() {
+        let ld = Snapshot::default().json_ld();
+        assert_eq!(ld.matches(r#""@type":"SoftwareApplication""#).count(), PRODUCTS.len());
+        assert_eq!(ld.matches(r#""@type":"Organization""#).count(), 1);
+        // Taglines lose their markup
+        assert!(ld.contains("100% of revenue from all projects"));
+        assert!(!ld.contains("**"));
+    }
+
+    #[test]
+    fn body_is_the_page_with_absolute_assets() {
+        let body = Snapshot::default().body();
+        assert!(body.starts_with(r#"<div class="alexandria-page" id="snapshot">"#));
+        assert!(body.contains(&format!(r#"src="{DEFAULT_SITE_URL}logos/projects/alex.png""#)));
+        assert!(Snapshot { behind_canvas: true, ..Snapshot::default() }.body().contains(r#"style="z-index:-1""#));
+    }
+
+    #[test]
+    fn index_html_has_the_current_snapshot() {
+        // Regenerate with the commands in the `Generated:` comments in index.html
+        let index = include_str!("../index.html");
+        let head = Snapshot::default().head();
+        for line in head.lines().chain([Snapshot::default().body().as_str()]) {
+            assert!(index.contains(line), "index.html is out of date, missing {line:?}");
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Open Graph cards for link previews
+//!
+//! Shared links unfurl with the 1200×630 image named in `og:image`. A
+//! [`SocialCard`] is laid out by the app itself, so it's set in the page's
+//! faces and palette with the same logos, tag chips, marked-up copy and glow
+//! border as the cards on the page, plus an ember glow behind it all. The
+//! `og-cards` binary renders one for the home page and one per product with
+//! [`crate::Offscreen`], named by [`SocialCard::file_name`].
+
+use egui::{Color32, FontFamily, FontId, Pos2, Rounding, Sense, Vec2};
+
+use crate::fonts::BrandFonts;
+use crate::galleys::Environment;
+use crate::schedule::RepaintSchedule;
+use crate::tokens::{DISPLAY_FAMILY, MONO_FAMILY};
+use crate::{install_style, AlexandriaApp, METRICS, PRODUCTS, SUBTITLE_TEXT};
+
+/// Size of a card in points, the size Open Graph and Twitter recommend
+pub const CARD_SIZE: Vec2 = Vec2::new(1200.0, 630.0);
+
+/// Inset of the glowing frame from the card's edge
+const FRAME_INSET: f32 = 32.0;
+
+/// Inset of the content from the card's edge
+const CONTENT_INSET: f32 = 80.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SocialCard {
+    Home,
+    /// Index into the products
+    Product(usize),
+}
+
+impl SocialCard {
+    /// The home card followed by one per product
+    pub fn all() -> impl Iterator<Item = SocialCard> {
+        std::iter::once(SocialCard::Home).chain((0..PRODUCTS.len()).map(SocialCard::Product))
+    }
+
+    /// `home.png`, or the product's name as a slug, e.g. `caffeine-launcher.png`
+    pub fn file_name(self) -> String {
+        match self {
+            SocialCard::Home => "home.png".to_owned(),
+            SocialCard::Product(idx) => format!("{}.png", slug(PRODUCTS[idx].name)),
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            SocialCard::Home => "Alexandria",
+            SocialCard::Product(idx) => PRODUCTS[idx].name,
+        }
+    }
+}
+
+/// Lowercase ASCII words joined by dashes (`lbry.app` → `lbry-app`)
+fn slug(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric()).filter(|w| !w.is_empty()).map(str::to_ascii_lowercase).collect::<Vec<_>>().join("-")
+}
+
+fn display(size: f32) -> FontId {
+    FontId::new(size, FontFamily::Name(DISPLAY_FAMILY.into()))
+}
+
+fn mono(size: f32) -> FontId {
+    FontId::new(size, FontFamily::Name(MONO_FAMILY.into()))
+}
+
+/// Soft radial glow fading from `color` at the center to nothing at `radius`
+fn paint_glow(painter: &egui::Painter, center: Pos2, radius: f32, color: Color32) {
+    const SEGMENTS: u32 = 64;
+    let mut mesh = egui::Mesh::default();
+    mesh.colored_vertex(center, color);
+    for i in 0..SEGMENTS {
+        let angle = i as f32 / SEGMENTS as f32 * std::f32::consts::TAU;
+        mesh.colored_vertex(center + Vec2::angled(angle) * radius, Color32::TRANSPARENT);
+        mesh.add_triangle(0, 1 + i, 1 + (i + 1) % SEGMENTS);
+    }
+    painter.add(mesh);
+}
+
+impl AlexandriaApp {
+    /// Lay out and paint `card` over the whole screen, with the glow animations
+    /// frozen at `time`
+    pub fn social_card(&mut self, ctx: &egui::Context, card: SocialCard, time: f64) {
+        // Named font families only bind from the frame after they're installed
+        if !BrandFonts::ready(ctx) {
+            self.fonts.install(ctx);
+            install_style(ctx);
+            ctx.request_repaint();
+            return;
+        }
+        self.fonts.poll(ctx, &self.assets);
+        self.repaint = RepaintSchedule::new(time);
+        let colors = self.colors;
+        let screen = ctx.screen_rect();
+        self.galleys.begin_frame(Environment {
+            width: screen.width(),
+            pixels_per_point: ctx.pixels_per_point(),
+            font_scale: self.font_scale,
+            fonts: self.fonts.generation(),
+            palette: colors,
+        });
+
+        let painter = ctx.layer_painter(egui::LayerId::background());
+        painter.rect_filled(screen, 0.0, colors.bg_primary);
+        let ember = |alpha| Color32::from_rgba_unmultiplied(colors.accent_ember.r(), colors.accent_ember.g(), colors.accent_ember.b(), alpha);
+        let rust = Color32::from_rgba_unmultiplied(colors.accent_rust.r(), colors.accent_rust.g(), colors.accent_rust.b(), 24);
+        paint_glow(&painter, screen.right_top() + Vec2::new(-160.0, 40.0), screen.height() * 0.9, rust);
+        paint_glow(&painter, screen.left_bottom() + Vec2::new(120.0, -20.0), screen.height() * 0.6, ember(16));
+        let frame = screen.shrink(FRAME_INSET);
+        self.draw_glow_border(&painter, frame, 1.0, time);
+
+        let content = screen.shrink(CONTENT_INSET);
+        egui::CentralPanel::default()
+            .frame(egui::Frame::none())
+            .show(ctx, |ui| {
+                ui.allocate_new_ui(egui::UiBuilder::new().max_rect(content), |ui| match card {
+                    SocialCard::Home => self.home_card(ui, time),
+                    SocialCard::Product(idx) => self.product_social_card(ui, idx, time),
+                });
+            });
+
+        // Product cards are signed along the bottom, with where to find the product
+        if let SocialCard::Product(idx) = card {
+            let brand = ctx.fonts(|f| f.layout_no_wrap("ALEXANDRIA".to_owned(), mono(16.0), colors.text_muted));
+            painter.galley(Pos2::new(content.left(), content.bottom() - brand.size().y), brand, colors.text_muted);
+            let host = PRODUCTS[idx].primary_url().trim_start_matches("https://").trim_end_matches('/').to_owned();
+            let host = ctx.fonts(|f| f.layout_no_wrap(host, mono(16.0), colors.accent_ember));
+            painter.galley(content.right_bottom() - host.size(), host, colors.accent_ember);
+        }
+
+        self.repaint.apply(ctx);
+    }
+
+    fn home_card(&mut self, ui: &mut egui::Ui, time: f64) {
+        let colors = self.colors;
+        ui.spacing_mut().item_spacing = Vec2::ZERO;
+        ui.label(self.galleys.no_wrap(ui, "Alexandria", display(112.0), colors.text_primary));
+        ui.add_space(8.0);
+        ui.label(self.galleys.no_wrap(ui, SUBTITLE_TEXT, display(40.0), colors.text_secondary));
+        ui.add_space(44.0);
+
+        // One logo per product
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 20.0;
+            for p in PRODUCTS {
+                let (rect, _) = ui.allocate_exact_size(Vec2::splat(64.0), Sense::hover());
+                self.paint_logo(ui, rect, p, time);
+            }
+        });
+        ui.add_space(44.0);
+
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 48.0;
+            for m in METRICS {
+                ui.vertical(|ui| {
+                    ui.label(self.galleys.no_wrap(ui, m.value, mono(36.0), colors.text_primary));
+                    ui.add_space(4.0);
+                    ui.label(self.galleys.label(ui, m.label, mono(14.0), colors.text_muted));
+                });
+            }
+        });
+    }
+
+    fn product_social_card(&mut self, ui: &mut egui::Ui, idx: usize, time: f64) {
+        let p = &PRODUCTS[idx];
+        let colors = self.colors;
+        ui.spacing_mut().item_spacing = Vec2::ZERO;
+        ui.horizontal(|ui| {
+            let (logo_rect, _) = ui.allocate_exact_size(Vec2::splat(160.0), Sense::hover());
+            self.paint_logo(ui, logo_rect, p, time);
+            ui.add_space(40.0);
+            ui.vertical(|ui| {
+                ui.add_space(20.0);
+                ui.label(self.galleys.no_wrap(ui, p.name, display(72.0), colors.text_primary));
+                ui.add_space(16.0);
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 10.0;
+                    for t in p.tags {
+                        let g = self.galleys.label(ui, t, mono(18.0), colors.tag_text);
+                        let (r, _) = ui.allocate_exact_size(g.size() + Vec2::new(20.0, 10.0), Sense::hover());
+                        ui.painter().rect_filled(r, Rounding::same(4.0), colors.tag_bg);
+                        ui.painter().galley(r.min + Vec2::new(10.0, 5.0), g, colors.tag_text);
+                    }
+                });
+            });
+        });
+        ui.add_space(48.0);
+        // Glossary terms and links keep their styling but nothing is interactive
+        let mut hotspots = Vec::new();
+        self.rich_label(ui, p.tagline, 34.0, colors.text_primary, &mut hotspots);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugs() {
+        assert_eq!(slug("lbry.app"), "lbry-app");
+        assert_eq!(slug("Caffeine Launcher"), "caffeine-launcher");
+        assert_eq!(slug("KongLocker"), "konglocker");
+    }
+
+    #[test]
+    fn file_names_are_distinct() {
+        let mut names: Vec<_> = SocialCard::all().map(SocialCard::file_name).collect();
+        assert_eq!(names[0], "home.png");
+        assert_eq!(names.len(), PRODUCTS.len() + 1);
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), PRODUCTS.len() + 1);
+    }
+}
@@ -0,0 +1,143 @@
+//! Startup errors and the HTML fallback shown in their place
+//!
+//! Anything that stops the canvas app from running (no canvas in the page, no
+//! WebGL, a panic at any point after the module loads) ends in
+//! [`show_fallback`], which hides the canvas and renders the page as plain DOM
+//! from [`crate::document`] with a short explanation at the top, so a visitor
+//! is never left with a blank screen.
+
+use std::fmt;
+
+use crate::document::Element;
+#[cfg(target_arch = "wasm32")]
+use crate::{assets::AssetResolver, document, ColorPalette};
+
+/// Id of the element the fallback is injected as, so it's only added once
+#[cfg(target_arch = "wasm32")]
+const FALLBACK_ID: &str = "fallback";
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum StartupError {
+    NoWindow,
+    NoDocument,
+    NoCanvas,
+    /// `#canvas` exists but isn't a `<canvas>`
+    NotACanvas,
+    WebGlUnavailable(String),
+    /// `WebRunner::start` failed for another reason
+    Runner(String),
+    /// Panicked at any point after the module loaded
+    Panicked(String),
+}
+
+impl StartupError {
+    /// Classify a `WebRunner::start` error message
+    pub fn from_runner(message: String) -> Self {
+        if message.contains("WebGL") {
+            StartupError::WebGlUnavailable(message)
+        } else {
+            StartupError::Runner(message)
+        }
+    }
+
+    /// What a visitor is told went wrong
+    fn summary(&self) -> &'static str {
+        match self {
+            StartupError::WebGlUnavailable(_) => "This page draws with WebGL, which isn't available in this browser. It may be turned off, or blocked by the graphics driver.",
+            StartupError::Panicked(_) => "Something went wrong while showing this page.",
+            _ => "This page couldn't start.",
+        }
+    }
+}
+
+impl fmt::Display for StartupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StartupError::NoWindow => write!(f, "no window"),
+            StartupError::NoDocument => write!(f, "no document"),
+            StartupError::NoCanvas => write!(f, "no element with id \"canvas\""),
+            StartupError::NotACanvas => write!(f, "#canvas is not a <canvas> element"),
+            StartupError::WebGlUnavailable(msg) => write!(f, "WebGL unavailable: {msg}"),
+            StartupError::Runner(msg) => write!(f, "failed to start: {msg}"),
+            StartupError::Panicked(msg) => write!(f, "panicked: {msg}"),
+        }
+    }
+}
+
+/// Explanation shown under the hero of the fallback page
+pub(crate) fn notice(error: &StartupError) -> Element {
+    Element::new("div")
+        .class("notice")
+        .attr("role", "alert")
+        .child(Element::new("p").text(error.summary()))
+        .child(Element::new("details").child(Element::new("summary").text("Details")).child(Element::new("code").text(error.to_string())))
+}
+
+/// Hide the canvas and show the fallback, unless it's already showing
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn show_fallback(error: &StartupError) {
+    let Some(doc) = web_sys::window().and_then(|w| w.document()) else { return };
+    if doc.get_element_by_id(FALLBACK_ID).is_some() {
+        return;
+    }
+    if let Some(canvas) = doc.get_element_by_id("canvas") {
+        let _ = canvas.set_attribute("style", "display:none");
+    }
+    let assets = AssetResolver::new(&crate::page_url(), crate::meta_content("asset-base").as_deref().or(crate::ASSET_BASE));
+    let page = document::page(&ColorPalette::default(), &assets, Some(notice(error))).attr("id", FALLBACK_ID);
+    match (page.to_dom(&doc), doc.body()) {
+        (Ok(page), Some(body)) => {
+            let _ = body.append_child(&page);
+        }
+        (Err(err), _) => log::error!("couldn't build the fallback page: {err:?}"),
+        (_, None) => log::error!("no <body> to show the fallback page in"),
+    }
+}
+
+/// Log panics to the console as before, then swap in the fallback. Installed
+/// before eframe's own panic handler, which chains to it.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        console_error_panic_hook::hook(info);
+        show_fallback(&StartupError::Panicked(info.to_string()));
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runner_errors_mentioning_webgl_are_classified() {
+        assert!(matches!(StartupError::from_runner("WebGL isn't supported".into()), StartupError::WebGlUnavailable(_)));
+        assert!(matches!(StartupError::from_runner("something else".into()), StartupError::Runner(_)));
+    }
+
+    #[test]
+    fn every_error_describes_itself() {
+        let errors = [
+            StartupError::NoWindow,
+            StartupError::NoDocument,
+            StartupError::NoCanvas,
+            StartupError::NotACanvas,
+            StartupError::WebGlUnavailable("no context".into()),
+            StartupError::Runner("no context".into()),
+            StartupError::Panicked("no context".into()),
+        ];
+        for error in errors {
+            assert!(!error.to_string().is_empty());
+            assert!(!error.summary().is_empty());
+        }
+        assert_eq!(StartupError::NotACanvas.to_string(), "#canvas is not a <canvas> element");
+    }
+
+    #[test]
+    fn notice_explains_and_keeps_the_detail() {
+        let error = StartupError::WebGlUnavailable("WebGL isn't supported".into());
+        let notice = notice(&error);
+        assert!(notice.attrs.contains(&("role", "alert".to_owned())));
+        let Some(crate::document::Node::Element(summary)) = notice.children.first() else { panic!("no summary") };
+        assert!(matches!(&summary.children[..], [crate::document::Node::Text(t)] if t.contains("WebGL")));
+    }
+}
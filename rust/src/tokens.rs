@@ -0,0 +1,284 @@
+//! Design tokens: named breakpoints and a fluid type scale
+//!
+//! Font sizes interpolate linearly with viewport width between
+//! [`FLUID_MIN_WIDTH`] and [`FLUID_MAX_WIDTH`], so text grows smoothly while
+//! resizing instead of snapping between tiers. Layout decisions that do need
+//! discrete steps (columns, metric cell width, footer icons and labels) hang off
+//! [`Breakpoint`] so every part of the page switches at the same widths.
+
+use egui::{FontFamily, FontId};
+
+/// Viewport width at and below which fluid sizes sit at their minimum
+pub(crate) const FLUID_MIN_WIDTH: f32 = 360.0;
+
+/// Viewport width at and above which fluid sizes sit at their maximum
+pub(crate) const FLUID_MAX_WIDTH: f32 = 1100.0;
+
+/// Named family for the hero and product names, bound in [`crate::fonts`]
+pub(crate) const DISPLAY_FAMILY: &str = "display";
+
+/// Named family for tags and metrics, bound in [`crate::fonts`]
+pub(crate) const MONO_FAMILY: &str = "mono";
+
+/// Extra letter spacing for uppercase labels, as a fraction of the font size
+pub(crate) const LABEL_TRACKING: f32 = 0.06;
+
+/// Range accepted for the user's font scale multiplier
+pub(crate) const FONT_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.75..=2.0;
+
+/// Viewport width tiers shared by typography and layout
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Breakpoint {
+    Mobile,
+    Tablet,
+    Desktop,
+    Wide,
+}
+
+impl Breakpoint {
+    pub const TABLET_MIN: f32 = 480.0;
+    pub const DESKTOP_MIN: f32 = 768.0;
+    pub const WIDE_MIN: f32 = 1200.0;
+
+    pub fn for_width(width: f32) -> Self {
+        if width < Self::TABLET_MIN {
+            Breakpoint::Mobile
+        } else if width < Self::DESKTOP_MIN {
+            Breakpoint::Tablet
+        } else if width < Self::WIDE_MIN {
+            Breakpoint::Desktop
+        } else {
+            Breakpoint::Wide
+        }
+    }
+
+    /// Lowercase name, for diagnostics
+    pub fn name(self) -> &'static str {
+        match self {
+            Breakpoint::Mobile => "mobile",
+            Breakpoint::Tablet => "tablet",
+            Breakpoint::Desktop => "desktop",
+            Breakpoint::Wide => "wide",
+        }
+    }
+
+    pub fn product_columns(self) -> usize {
+        match self {
+            Breakpoint::Mobile => 1,
+            Breakpoint::Tablet | Breakpoint::Desktop => 2,
+            Breakpoint::Wide => 3,
+        }
+    }
+
+    /// Width of one cell in the metrics row
+    pub fn metric_width(self) -> f32 {
+        match self {
+            Breakpoint::Mobile => 70.0,
+            _ => 120.0,
+        }
+    }
+
+    /// Footer icon size and the gap between icons
+    pub fn footer_icon(self) -> (f32, f32) {
+        match self {
+            Breakpoint::Mobile => (24.0, 8.0),
+            _ => (28.0, 12.0),
+        }
+    }
+
+    /// Whether footer links show their title next to the icon
+    pub fn footer_labels(self) -> bool {
+        self >= Breakpoint::Desktop
+    }
+}
+
+/// A size that scales linearly with viewport width
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Fluid {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Fluid {
+    pub const fn new(min: f32, max: f32) -> Self {
+        Self { min, max }
+    }
+
+    pub fn at(self, width: f32) -> f32 {
+        let t = ((width - FLUID_MIN_WIDTH) / (FLUID_MAX_WIDTH - FLUID_MIN_WIDTH)).clamp(0.0, 1.0);
+        self.min + (self.max - self.min) * t
+    }
+}
+
+/// The type scale, from the smallest to the largest viewport
+pub(crate) struct TypeScale {
+    pub title: Fluid,
+    pub subtitle: Fluid,
+    pub metric_value: Fluid,
+    pub metric_label: Fluid,
+    pub product_name: Fluid,
+    pub tagline: Fluid,
+    pub description: Fluid,
+    pub tag: Fluid,
+}
+
+pub(crate) const TYPE_SCALE: TypeScale = TypeScale {
+    title: Fluid::new(28.0, 52.0),
+    subtitle: Fluid::new(14.0, 20.0),
+    metric_value: Fluid::new(18.0, 26.0),
+    metric_label: Fluid::new(9.0, 11.0),
+    product_name: Fluid::new(15.0, 18.0),
+    tagline: Fluid::new(12.0, 14.0),
+    description: Fluid::new(11.0, 13.0),
+    tag: Fluid::new(8.0, 9.0),
+};
+
+/// Font sizes resolved for one viewport width and font scale
+#[derive(Debug, PartialEq)]
+pub(crate) struct Typography {
+    pub title_size: f32,
+    pub subtitle_size: f32,
+    pub metric_value_size: f32,
+    pub metric_label_size: f32,
+    pub product_name_size: f32,
+    pub tagline_size: f32,
+    pub description_size: f32,
+    pub tag_size: f32,
+}
+
+impl Typography {
+    /// Resolve the type scale at `width`, multiplied by the user's `font_scale`
+    pub fn for_width(width: f32, font_scale: f32) -> Self {
+        let scale = font_scale.clamp(*FONT_SCALE_RANGE.start(), *FONT_SCALE_RANGE.end());
+        let size = |f: Fluid| f.at(width) * scale;
+        let s = &TYPE_SCALE;
+        Self {
+            title_size: size(s.title),
+            subtitle_size: size(s.subtitle),
+            metric_value_size: size(s.metric_value),
+            metric_label_size: size(s.metric_label),
+            product_name_size: size(s.product_name),
+            tagline_size: size(s.tagline),
+            description_size: size(s.description),
+            tag_size: size(s.tag),
+        }
+    }
+
+    pub fn title_font(&self) -> FontId {
+        FontId::new(self.title_size, FontFamily::Name(DISPLAY_FAMILY.into()))
+    }
+
+    pub fn product_name_font(&self) -> FontId {
+        FontId::new(self.product_name_size, FontFamily::Name(DISPLAY_FAMILY.into()))
+    }
+
+    pub fn metric_value_font(&self) -> FontId {
+        FontId::new(self.metric_value_size, FontFamily::Name(MONO_FAMILY.into()))
+    }
+
+    pub fn metric_label_font(&self) -> FontId {
+        FontId::new(self.metric_label_size, FontFamily::Name(MONO_FAMILY.into()))
+    }
+
+    pub fn tag_font(&self) -> FontId {
+        FontId::new(self.tag_size, FontFamily::Name(MONO_FAMILY.into()))
+    }
+
+    /// Letter spacing in points for an uppercase label set in `font`
+    pub fn label_spacing(font: &FontId) -> f32 {
+        font.size * LABEL_TRACKING
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn breakpoints() {
+        assert_eq!(Breakpoint::for_width(320.0), Breakpoint::Mobile);
+        assert_eq!(Breakpoint::for_width(479.9), Breakpoint::Mobile);
+        assert_eq!(Breakpoint::for_width(480.0), Breakpoint::Tablet);
+        assert_eq!(Breakpoint::for_width(768.0), Breakpoint::Desktop);
+        assert_eq!(Breakpoint::for_width(1199.0), Breakpoint::Desktop);
+        assert_eq!(Breakpoint::for_width(1920.0), Breakpoint::Wide);
+    }
+
+    #[test]
+    fn fluid_sizes_clamp_outside_the_range() {
+        let f = Fluid::new(10.0, 20.0);
+        assert_eq!(f.at(0.0), 10.0);
+        assert_eq!(f.at(FLUID_MIN_WIDTH), 10.0);
+        assert_eq!(f.at(FLUID_MAX_WIDTH), 20.0);
+        assert_eq!(f.at(4000.0), 20.0);
+    }
+
+    #[test]
+    fn fluid_sizes_interpolate() {
+        let f = Fluid::new(10.0, 20.0);
+        let mid = (FLUID_MIN_WIDTH + FLUID_MAX_WIDTH) / 2.0;
+        assert!(approx(f.at(mid), 15.0));
+        assert!(approx(f.at(FLUID_MIN_WIDTH + (FLUID_MAX_WIDTH - FLUID_MIN_WIDTH) * 0.25), 12.5));
+    }
+
+    #[test]
+    fn hero_title_at_sample_widths() {
+        let title = |w| Typography::for_width(w, 1.0).title_size;
+        assert!(approx(title(320.0), 28.0));
+        assert!(approx(title(360.0), 28.0));
+        assert!(approx(title(730.0), 40.0));
+        assert!(approx(title(1100.0), 52.0));
+        assert!(approx(title(1920.0), 52.0));
+    }
+
+    #[test]
+    fn sizes_grow_smoothly_across_breakpoints() {
+        // No jump at the old 480/768 tiers: one pixel wider is barely larger
+        for edge in [Breakpoint::TABLET_MIN, Breakpoint::DESKTOP_MIN] {
+            let below = Typography::for_width(edge - 1.0, 1.0);
+            let above = Typography::for_width(edge, 1.0);
+            assert!(above.title_size >= below.title_size);
+            assert!(above.title_size - below.title_size < 0.1);
+        }
+    }
+
+    #[test]
+    fn full_scale_on_desktop() {
+        assert_eq!(
+            Typography::for_width(1280.0, 1.0),
+            Typography {
+                title_size: 52.0,
+                subtitle_size: 20.0,
+                metric_value_size: 26.0,
+                metric_label_size: 11.0,
+                product_name_size: 18.0,
+                tagline_size: 14.0,
+                description_size: 13.0,
+                tag_size: 9.0,
+            }
+        );
+    }
+
+    #[test]
+    fn fonts_use_named_families() {
+        let typo = Typography::for_width(800.0, 1.0);
+        assert_eq!(typo.title_font().family, FontFamily::Name(DISPLAY_FAMILY.into()));
+        assert_eq!(typo.tag_font().family, FontFamily::Name(MONO_FAMILY.into()));
+        assert_eq!(typo.metric_label_font().size, typo.metric_label_size);
+        assert!(approx(Typography::label_spacing(&typo.tag_font()), typo.tag_size * LABEL_TRACKING));
+    }
+
+    #[test]
+    fn font_scale_multiplies_and_clamps() {
+        let base = Typography::for_width(600.0, 1.0);
+        let scaled = Typography::for_width(600.0, 1.5);
+        assert!(approx(scaled.description_size, base.description_size * 1.5));
+        assert!(approx(scaled.tag_size, base.tag_size * 1.5));
+        assert_eq!(Typography::for_width(600.0, 10.0), Typography::for_width(600.0, 2.0));
+        assert_eq!(Typography::for_width(600.0, 0.1), Typography::for_width(600.0, 0.75));
+    }
+}
@@ -0,0 +1,164 @@
+//! Pausing animation while the page can't be seen
+//!
+//! Animations run on a [`PauseClock`] that stops while the browser tab is
+//! hidden or the canvas is scrolled out of view in its host page. Paused spans
+//! are measured from the visibility callbacks themselves, because browsers stop
+//! delivering frames to hidden tabs, so the first frame after coming back sees
+//! no gap and nothing jumps.
+
+/// Wall-clock time with paused spans cut out
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct PauseClock {
+    paused_total: f64,
+    paused_since: Option<f64>,
+}
+
+impl PauseClock {
+    // Only browser callbacks pause the clock; native builds just read it
+    #[cfg(any(test, target_arch = "wasm32"))]
+    pub fn pause(&mut self, wall: f64) {
+        self.paused_since.get_or_insert(wall);
+    }
+
+    #[cfg(any(test, target_arch = "wasm32"))]
+    pub fn resume(&mut self, wall: f64) {
+        if let Some(since) = self.paused_since.take() {
+            self.paused_total += (wall - since).max(0.0);
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_since.is_some()
+    }
+
+    /// Clock time at `wall`, frozen while paused
+    pub fn time(&self, wall: f64) -> f64 {
+        let paused_now = self.paused_since.map_or(0.0, |since| (wall - since).max(0.0));
+        wall - self.paused_total - paused_now
+    }
+}
+
+/// Why the page is or isn't visible, updated from browser callbacks
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Visibility {
+    #[cfg(any(test, target_arch = "wasm32"))]
+    pub tab_hidden: bool,
+    #[cfg(any(test, target_arch = "wasm32"))]
+    pub offscreen: bool,
+    pub clock: PauseClock,
+}
+
+#[cfg(any(test, target_arch = "wasm32"))]
+impl Visibility {
+    /// Apply a change in either flag, pausing or resuming the clock at `wall`
+    pub fn update(&mut self, wall: f64, change: impl FnOnce(&mut Self)) {
+        change(self);
+        if self.tab_hidden || self.offscreen {
+            self.clock.pause(wall);
+        } else {
+            self.clock.resume(wall);
+        }
+    }
+}
+
+/// Watch tab visibility and the canvas's intersection with the viewport,
+/// repainting when the page becomes visible again
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn observe(ctx: &egui::Context, state: &std::rc::Rc<std::cell::RefCell<Visibility>>) {
+    use wasm_bindgen::{closure::Closure, JsCast};
+
+    let Some(doc) = web_sys::window().and_then(|w| w.document()) else { return };
+
+    let wake = {
+        let ctx = ctx.clone();
+        let state = state.clone();
+        move |change: &dyn Fn(&mut Visibility)| {
+            let mut state = state.borrow_mut();
+            state.update(crate::now(), change);
+            if !state.clock.is_paused() {
+                ctx.request_repaint();
+            }
+        }
+    };
+
+    let on_visibility = {
+        let wake = wake.clone();
+        let doc = doc.clone();
+        Closure::<dyn FnMut()>::new(move || {
+            let hidden = doc.hidden();
+            wake(&|v: &mut Visibility| v.tab_hidden = hidden);
+        })
+    };
+    let _ = doc.add_event_listener_with_callback("visibilitychange", on_visibility.as_ref().unchecked_ref());
+    on_visibility.forget();
+    state.borrow_mut().update(crate::now(), |v| v.tab_hidden = doc.hidden());
+
+    let Some(canvas) = doc.get_element_by_id("canvas") else { return };
+    let on_intersect = Closure::<dyn FnMut(js_sys::Array)>::new(move |entries: js_sys::Array| {
+        let visible = entries
+            .iter()
+            .filter_map(|e| e.dyn_into::<web_sys::IntersectionObserverEntry>().ok())
+            .next_back()
+            .map(|e| e.is_intersecting());
+        if let Some(visible) = visible {
+            wake(&|v: &mut Visibility| v.offscreen = !visible);
+        }
+    });
+    if let Ok(observer) = web_sys::IntersectionObserver::new(on_intersect.as_ref().unchecked_ref()) {
+        observer.observe(&canvas);
+    }
+    on_intersect.forget();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_with_wall_time_until_paused() {
+        let clock = PauseClock::default();
+        assert_eq!(clock.time(3.5), 3.5);
+    }
+
+    #[test]
+    fn freezes_while_paused() {
+        let mut clock = PauseClock::default();
+        clock.pause(10.0);
+        assert_eq!(clock.time(10.0), 10.0);
+        assert_eq!(clock.time(25.0), 10.0);
+    }
+
+    #[test]
+    fn resumes_without_a_jump() {
+        let mut clock = PauseClock::default();
+        clock.pause(10.0);
+        clock.resume(70.0);
+        assert_eq!(clock.time(70.0), 10.0);
+        assert_eq!(clock.time(71.0), 11.0);
+        clock.pause(80.0);
+        clock.resume(90.0);
+        assert_eq!(clock.time(91.0), 21.0);
+    }
+
+    #[test]
+    fn repeated_pause_keeps_the_first_start() {
+        let mut clock = PauseClock::default();
+        clock.pause(5.0);
+        clock.pause(8.0);
+        clock.resume(9.0);
+        assert_eq!(clock.time(9.0), 5.0);
+    }
+
+    #[test]
+    fn either_reason_pauses() {
+        let mut v = Visibility::default();
+        v.update(1.0, |v| v.tab_hidden = true);
+        assert!(v.clock.is_paused());
+        v.update(2.0, |v| v.offscreen = true);
+        v.update(3.0, |v| v.tab_hidden = false);
+        assert!(v.clock.is_paused(), "still offscreen");
+        v.update(4.0, |v| v.offscreen = false);
+        assert!(!v.clock.is_paused());
+        assert_eq!(v.clock.time(4.0), 1.0);
+    }
+}